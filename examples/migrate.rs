@@ -0,0 +1,52 @@
+use light_id::LightId;
+
+/**
+ * Output:
+ * a
+ * b
+ * c
+ * d
+ * e
+ * f
+ * ba
+ * bb
+ * bc
+ * bd
+ * --- migrate charset ---
+ * 100
+ * 101
+ * 102
+ * 10a
+ * 10b
+ * 10c
+ * 110
+ * 111
+ * 112
+ * 11a
+ * --- decode both epochs ---
+ * ba -> 6
+ * 10a -> 13
+ * **/
+fn main () {
+  let mut generator = LightId::from("abcdef");
+
+  for _ in 0..10 {
+    println!("{}", generator.next());
+  }
+
+  println!("--- migrate charset ---");
+
+  // Unlike `chars`, `migrate` never issues an id equal to a previous one:
+  // the new ids are longer than all the ids issued so far
+  generator.migrate("012abc");
+
+  for _ in 0..10 {
+    println!("{}", generator.next());
+  }
+
+  println!("--- decode both epochs ---");
+
+  for id in ["ba", "10a"] {
+    println!("{} -> {}", id, generator.index(id));
+  }
+}
@@ -0,0 +1,21 @@
+use light_id::{IdSwitcher, LightId, Preset};
+
+/**
+ * Output:
+ * 1
+ * 2
+ * 3
+ * 1000 -> 3e8
+ * 
+ * **/
+fn main () {
+  let mut generator = LightId::with_preset(Preset::Base58);
+
+  for _ in 0..3 {
+    println!("{}", generator.next());
+  }
+
+  let switcher = IdSwitcher::new(Preset::Base10, Preset::Base16);
+
+  println!("1000 -> {}", switcher.switch("1000"));
+}
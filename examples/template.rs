@@ -0,0 +1,24 @@
+use light_id::Template;
+
+/**
+ * Output:
+ * INV-2024-000000-0
+ * INV-2024-000001-8
+ * INV-2024-000002-6
+ * INV-2024-000002-6 = #2
+ * 
+ * **/
+fn main () {
+  let mut template = Template::new("INV-{year}-{seq:6:0-9}-{check}").unwrap();
+
+  // Defaults to the current year
+  template.set("year", "2024");
+
+  for _ in 0..3 {
+    println!("{}", template.next());
+  }
+
+  let parsed = template.parse("INV-2024-000002-6").unwrap();
+
+  println!("INV-2024-000002-6 = #{}", parsed.index);
+}
@@ -0,0 +1,27 @@
+use light_id::{Alphabet, LightId};
+
+/**
+ * Output:
+ * brave-otter
+ * brave-panda
+ * brave-heron
+ * calm-otter
+ * calm-panda
+ * calm-otter = #3
+ * 
+ * **/
+fn main () {
+  let adjectives = ["brave", "calm"];
+  let animals = ["otter", "panda", "heron"];
+
+  // Every combination of an adjective and an animal, in order
+  let words = adjectives.iter().flat_map(|a| animals.iter().map(move |b| format!("{}-{}", a, b)));
+
+  let mut generator = LightId::from(Alphabet::words(words, " "));
+
+  for _ in 0..5 {
+    println!("{}", generator.next());
+  }
+
+  println!("calm-otter = #{}", generator.index("calm-otter"));
+}
@@ -0,0 +1,232 @@
+use std::collections::BTreeMap;
+
+use crate::{LightId, ParseError};
+
+/// Hands out the smallest free id and takes ids back, so that ids stay short
+/// when they are reused, such as session or slot handles.
+///
+/// The released ids are kept as ranges, so that the memory used depends on the
+/// number of gaps rather than on the number of released ids.
+/// ```
+/// use light_id::{IdAllocator, LightId};
+///
+/// let mut allocator = IdAllocator::new(LightId::new());
+///
+/// assert_eq!(Some("0".to_string()), allocator.allocate());
+/// assert_eq!(Some("1".to_string()), allocator.allocate());
+/// assert_eq!(Some("2".to_string()), allocator.allocate());
+///
+/// assert!(allocator.release("1"));
+///
+/// assert_eq!(Some("1".to_string()), allocator.allocate());
+/// assert_eq!(Some("3".to_string()), allocator.allocate());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdAllocator {
+    generator: LightId,
+    // Free ranges below `next`, from their start to their end (excluded)
+    free: BTreeMap<usize, usize>,
+    next: usize,
+    allocated: usize,
+}
+
+impl IdAllocator {
+    /// Creates a new [`IdAllocator`], formatting the ids with `generator`
+    /// and ignoring its own count.
+    /// ```
+    /// use light_id::{IdAllocator, LightId};
+    ///
+    /// let allocator = IdAllocator::new(LightId::from("abc"));
+    /// ```
+    pub fn new(generator: LightId) -> Self {
+        IdAllocator {
+            generator,
+            free: BTreeMap::new(),
+            next: 0,
+            allocated: 0,
+        }
+    }
+
+    /// Returns the smallest free id and marks it as allocated, or `None` if
+    /// the capacity of the generator is exhausted. The ids blocked by the
+    /// [`Blocklist`](crate::Blocklist) of the generator are never allocated.
+    /// ```
+    /// use light_id::{IdAllocator, LightId};
+    ///
+    /// let mut generator = LightId::from("01");
+    /// generator.max(1);
+    ///
+    /// let mut allocator = IdAllocator::new(generator);
+    ///
+    /// assert_eq!(Some("0".to_string()), allocator.allocate());
+    /// assert_eq!(Some("1".to_string()), allocator.allocate());
+    /// assert_eq!(None, allocator.allocate());
+    /// ```
+    pub fn allocate(&mut self) -> Option<String> {
+        let n = match self.free.pop_first() {
+            Some((start, end)) => {
+                if start + 1 < end {
+                    self.free.insert(start + 1, end);
+                }
+                start
+            }
+            // The blocked positions are skipped for good, never being allocated
+            None => loop {
+                if self.generator.exceeds(self.next) {
+                    return None;
+                }
+
+                self.next += 1;
+
+                if !self.generator.is_blocked_at(self.next - 1) {
+                    break self.next - 1;
+                }
+            },
+        };
+
+        self.allocated += 1;
+        Some(self.generator.nth(n))
+    }
+
+    /// Takes an id back, returning whether it was allocated.
+    /// ```
+    /// use light_id::{IdAllocator, LightId};
+    ///
+    /// let mut allocator = IdAllocator::new(LightId::new());
+    ///
+    /// allocator.allocate();
+    ///
+    /// assert!(allocator.release("0"));
+    /// assert!(!allocator.release("0"));
+    /// ```
+    pub fn release<S: AsRef<str>>(&mut self, id: S) -> bool {
+        self.try_release(id).expect("Invalid id")
+    }
+
+    /// Takes an id back, returning whether it was allocated, or the reason it cannot be parsed.
+    /// ```
+    /// use light_id::{IdAllocator, LightId, ParseError};
+    ///
+    /// let mut allocator = IdAllocator::new(LightId::from("abc"));
+    ///
+    /// assert_eq!(Ok(false), allocator.try_release("a"));
+    /// assert_eq!(Err(ParseError::InvalidCharacter('d')), allocator.try_release("d"));
+    /// ```
+    pub fn try_release<S: AsRef<str>>(&mut self, id: S) -> Result<bool, ParseError> {
+        let n = self.generator.try_index(id)?;
+
+        if !self.contains(n) {
+            return Ok(false);
+        }
+
+        let mut start = n;
+        let mut end = n + 1;
+
+        if let Some((&before, _)) = self.free.range(..n).next_back().filter(|(_, e)| **e == n) {
+            self.free.remove(&before);
+            start = before;
+        }
+
+        if let Some(after) = self.free.remove(&end) {
+            end = after;
+        }
+
+        if end == self.next {
+            self.next = start;
+        } else {
+            self.free.insert(start, end);
+        }
+
+        self.allocated -= 1;
+        Ok(true)
+    }
+
+    /// Returns whether an id is allocated.
+    /// ```
+    /// use light_id::{IdAllocator, LightId};
+    ///
+    /// let mut allocator = IdAllocator::new(LightId::new());
+    ///
+    /// allocator.allocate();
+    ///
+    /// assert!(allocator.is_allocated("0"));
+    /// assert!(!allocator.is_allocated("1"));
+    /// ```
+    pub fn is_allocated<S: AsRef<str>>(&self, id: S) -> bool {
+        self.generator
+            .try_index(id)
+            .is_ok_and(|n| self.contains(n))
+    }
+
+    /// Returns the number of allocated ids.
+    /// ```
+    /// use light_id::{IdAllocator, LightId};
+    ///
+    /// let mut allocator = IdAllocator::new(LightId::new());
+    ///
+    /// allocator.allocate();
+    /// allocator.allocate();
+    ///
+    /// assert_eq!(2, allocator.allocated());
+    /// ```
+    pub fn allocated(&self) -> usize {
+        self.allocated
+    }
+
+    /// Returns the number of gaps left by released ids.
+    /// ```
+    /// use light_id::{IdAllocator, LightId};
+    ///
+    /// let mut allocator = IdAllocator::new(LightId::new());
+    ///
+    /// for _ in 0..10 {
+    ///     allocator.allocate();
+    /// }
+    ///
+    /// allocator.release("2");
+    /// allocator.release("3");
+    /// allocator.release("6");
+    ///
+    /// assert_eq!(2, allocator.free_ranges());
+    /// ```
+    pub fn free_ranges(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Returns the share of the ids up to the largest allocated one that are
+    /// allocated, `1.0` meaning there is no gap.
+    /// ```
+    /// use light_id::{IdAllocator, LightId};
+    ///
+    /// let mut allocator = IdAllocator::new(LightId::new());
+    ///
+    /// for _ in 0..4 {
+    ///     allocator.allocate();
+    /// }
+    ///
+    /// allocator.release("1");
+    ///
+    /// assert_eq!(0.75, allocator.utilization());
+    /// ```
+    pub fn utilization(&self) -> f64 {
+        match self.next {
+            0 => 1.0,
+            next => self.allocated as f64 / next as f64,
+        }
+    }
+
+    /// Returns the generator formatting the ids.
+    pub fn generator(&self) -> &LightId {
+        &self.generator
+    }
+
+    fn contains(&self, n: usize) -> bool {
+        n < self.next
+            && !self.generator.is_blocked_at(n)
+            && self
+                .free
+                .range(..=n)
+                .next_back()
+                .is_none_or(|(_, end)| *end <= n)
+    }
+}
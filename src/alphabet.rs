@@ -0,0 +1,290 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::Preset;
+
+/// The symbols of a custom base, in their order of importance, along with
+/// the normalization rules applied when parsing ids.
+/// Each symbol is either a single grapheme, so accented letters or emojis made
+/// of several code points (such as flags) are supported, or a whole word when
+/// the alphabet is built with [`Alphabet::words`].
+/// ```
+/// use light_id::{Alphabet, LightId};
+///
+/// let mut alphabet = Alphabet::new("0123456789ABCDEF");
+/// alphabet.case_insensitive(true).ignore('-');
+///
+/// let generator = LightId::from(alphabet);
+///
+/// assert_eq!(0xff, generator.index("f-F"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Alphabet {
+    symbols: Vec<String>,
+    case_insensitive: bool,
+    aliases: Vec<(String, String)>,
+    ignored: Vec<String>,
+    separator: String,
+}
+
+impl Alphabet {
+    /// Creates a new [`Alphabet`] without any normalization rule.
+    /// ```
+    /// use light_id::Alphabet;
+    ///
+    /// let alphabet = Alphabet::new("abc");
+    ///
+    /// assert_eq!(3, alphabet.len());
+    /// ```
+    pub fn new<S: AsRef<str>>(characters: S) -> Self {
+        Alphabet {
+            symbols: characters.as_ref().graphemes(true).map(String::from).collect(),
+            case_insensitive: false,
+            aliases: vec![],
+            ignored: vec![],
+            separator: String::new(),
+        }
+    }
+
+    /// Creates a new [`Alphabet`] whose symbols are words, joined by `separator`
+    /// in the ids.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the separator is empty, or if a word is empty or contains the separator.
+    /// ```
+    /// use light_id::{Alphabet, LightId};
+    ///
+    /// let alphabet = Alphabet::words(["apple", "banana", "cherry"], "-");
+    /// let mut generator = LightId::from(alphabet);
+    ///
+    /// generator.skip(5);
+    ///
+    /// assert_eq!("banana-cherry", generator.current());
+    /// assert_eq!(5, generator.index("banana-cherry"));
+    /// ```
+    pub fn words<I, S>(words: I, separator: &str) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        assert!(!separator.is_empty(), "The separator cannot be empty");
+
+        let symbols: Vec<String> = words.into_iter().map(Into::into).collect();
+
+        for symbol in &symbols {
+            assert!(
+                !symbol.is_empty() && !symbol.contains(separator),
+                "Invalid word {:?}",
+                symbol
+            );
+        }
+
+        Alphabet {
+            symbols,
+            case_insensitive: false,
+            aliases: vec![],
+            ignored: vec![],
+            separator: separator.to_string(),
+        }
+    }
+
+    /// Returns the symbols, in their order of importance.
+    /// ```
+    /// use light_id::Alphabet;
+    ///
+    /// assert_eq!(&["a", "é", "🇫🇷"], Alphabet::new("aé🇫🇷").symbols());
+    /// ```
+    pub fn symbols(&self) -> &[String] {
+        &self.symbols
+    }
+
+    /// Returns the string joining the symbols of an id, empty unless the
+    /// alphabet is made of words.
+    /// ```
+    /// use light_id::Alphabet;
+    ///
+    /// assert_eq!("", Alphabet::new("abc").separator());
+    /// assert_eq!(" ", Alphabet::words(["a", "b"], " ").separator());
+    /// ```
+    pub fn separator(&self) -> &str {
+        &self.separator
+    }
+
+    /// Returns the number of symbols, which is the base of the ids.
+    /// ```
+    /// use light_id::Alphabet;
+    ///
+    /// assert_eq!(16, Alphabet::new("0123456789abcdef").len());
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// Returns whether the symbols are in code-point order, none being the start
+    /// of another, so that ids of the same length sort like their values.
+    /// ```
+    /// use light_id::{Alphabet, Preset};
+    ///
+    /// assert!(Alphabet::new("0123456789abcdef").is_sorted());
+    /// assert!(!Alphabet::from(Preset::Base62).is_sorted());
+    /// assert!(!Alphabet::words(["a", "ab"], "-").is_sorted());
+    /// ```
+    pub fn is_sorted(&self) -> bool {
+        self.symbols
+            .windows(2)
+            .all(|pair| pair[0] < pair[1] && !pair[1].starts_with(&pair[0]))
+    }
+
+    /// Puts the symbols in code-point order.
+    /// ```
+    /// use light_id::{Alphabet, Preset};
+    ///
+    /// let mut alphabet = Alphabet::from(Preset::Base62);
+    /// alphabet.sort();
+    ///
+    /// assert!(alphabet.is_sorted());
+    /// assert_eq!(["0", "1"], alphabet.symbols()[..2]);
+    /// assert_eq!(["A", "B"], alphabet.symbols()[10..12]);
+    /// ```
+    pub fn sort(&mut self) -> &mut Self {
+        self.symbols.sort();
+        self
+    }
+
+    /// Accepts the lowercase and uppercase variants of the symbols when parsing.
+    /// ```
+    /// use light_id::{Alphabet, LightId};
+    ///
+    /// let mut alphabet = Alphabet::new("0123456789abcdef");
+    /// alphabet.case_insensitive(true);
+    ///
+    /// assert_eq!(10, LightId::from(alphabet).index("A"));
+    /// ```
+    pub fn case_insensitive(&mut self, enabled: bool) -> &mut Self {
+        self.case_insensitive = enabled;
+        self
+    }
+
+    /// Reads `from` as if it were `to` when parsing.
+    /// ```
+    /// use light_id::{Alphabet, LightId};
+    ///
+    /// let mut alphabet = Alphabet::new("0123456789");
+    /// alphabet.alias('O', '0');
+    ///
+    /// assert_eq!(10, LightId::from(alphabet).index("1O"));
+    /// ```
+    pub fn alias<F: Into<String>, T: Into<String>>(&mut self, from: F, to: T) -> &mut Self {
+        self.aliases.push((from.into(), to.into()));
+        self
+    }
+
+    /// Skips `symbol` when parsing, typically a separator such as `-`.
+    /// ```
+    /// use light_id::{Alphabet, LightId};
+    ///
+    /// let mut alphabet = Alphabet::new("0123456789");
+    /// alphabet.ignore('-');
+    ///
+    /// assert_eq!(1234, LightId::from(alphabet).index("12-34"));
+    /// ```
+    pub fn ignore<S: Into<String>>(&mut self, symbol: S) -> &mut Self {
+        self.ignored.push(symbol.into());
+        self
+    }
+
+    /// Splits an id into its symbols.
+    pub(crate) fn split<'a>(&self, id: &'a str) -> Vec<&'a str> {
+        match self.separator.as_str() {
+            "" => id.graphemes(true).collect(),
+            separator => id.split(separator).collect(),
+        }
+    }
+
+    /// Returns the value of a symbol once normalized, or `Some(None)` if it
+    /// is ignored. Returns `None` when the symbol is unknown.
+    pub(crate) fn position(&self, symbol: &str) -> Option<Option<usize>> {
+        if self.ignored.iter().any(|i| i == symbol) {
+            return Some(None);
+        }
+
+        let lookup = |s: &str| {
+            let s = self
+                .aliases
+                .iter()
+                .find(|(from, _)| from == s)
+                .map_or(s, |(_, to)| to.as_str());
+
+            self.symbols.iter().position(|i| i == s)
+        };
+
+        if let Some(index) = lookup(symbol) {
+            return Some(Some(index));
+        }
+
+        if self.case_insensitive {
+            for variant in [symbol.to_lowercase(), symbol.to_uppercase()] {
+                if variant != symbol {
+                    if let Some(index) = lookup(&variant) {
+                        return Some(Some(index));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl From<&str> for Alphabet {
+    fn from(characters: &str) -> Self {
+        Alphabet::new(characters)
+    }
+}
+
+impl From<&String> for Alphabet {
+    fn from(characters: &String) -> Self {
+        Alphabet::new(characters)
+    }
+}
+
+impl From<String> for Alphabet {
+    fn from(characters: String) -> Self {
+        Alphabet::new(characters)
+    }
+}
+
+impl From<&Alphabet> for Alphabet {
+    fn from(alphabet: &Alphabet) -> Self {
+        alphabet.clone()
+    }
+}
+
+impl From<Preset> for Alphabet {
+    fn from(preset: Preset) -> Self {
+        let mut alphabet = Alphabet::new(preset.characters());
+
+        match preset {
+            Preset::Crockford => {
+                alphabet
+                    .case_insensitive(true)
+                    .alias('O', '0')
+                    .alias('I', '1')
+                    .alias('L', '1')
+                    .ignore('-');
+            }
+            Preset::Base16
+            | Preset::Base16Upper
+            | Preset::Base32
+            | Preset::Base32Hex
+            | Preset::ZBase32
+            | Preset::Base36 => {
+                alphabet.case_insensitive(true);
+            }
+            _ => {}
+        }
+
+        alphabet
+    }
+}
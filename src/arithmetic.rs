@@ -0,0 +1,259 @@
+use std::cmp::Ordering;
+
+use crate::{utils, LightId, ParseError};
+
+/// Arithmetic on ids, computed digit by digit so that ids of any length can be
+/// used, as long as they belong to the sequence of the generator.
+impl LightId {
+    /// Returns the id `n` positions after `id`.
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let generator = LightId::from("0123456789");
+    ///
+    /// assert_eq!("1499", generator.add("999", 500));
+    /// assert_eq!("100000000000000000000", generator.add("99999999999999999999", 1));
+    /// ```
+    pub fn add<S: AsRef<str>>(&self, id: S, n: usize) -> String {
+        self.try_add(id, n).expect("Invalid id")
+    }
+
+    /// Returns the id `n` positions after `id`, or the reason it cannot be computed.
+    /// ```
+    /// use light_id::{LightId, ParseError};
+    ///
+    /// let mut generator = LightId::from("0123456789");
+    ///
+    /// generator.max(3);
+    ///
+    /// assert_eq!(Ok("999".to_string()), generator.try_add("998", 1));
+    /// assert_eq!(Err(ParseError::Overflow), generator.try_add("998", 2));
+    /// ```
+    pub fn try_add<S: AsRef<str>>(&self, id: S, n: usize) -> Result<String, ParseError> {
+        let base = self.alphabet.len();
+        let position = self.try_position(id.as_ref())?;
+
+        self.render_position(&utils::add_digits(&position, &utils::to_digits(n, base), base))
+    }
+
+    /// Returns the id `n` positions before `id`.
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let generator = LightId::from("0123456789");
+    ///
+    /// assert_eq!("999", generator.sub("1499", 500));
+    /// ```
+    pub fn sub<S: AsRef<str>>(&self, id: S, n: usize) -> String {
+        self.try_sub(id, n).expect("Invalid id")
+    }
+
+    /// Returns the id `n` positions before `id`, or the reason it cannot be computed.
+    /// ```
+    /// use light_id::{LightId, ParseError};
+    ///
+    /// let generator = LightId::from("0123456789");
+    ///
+    /// assert_eq!(Ok("0".to_string()), generator.try_sub("5", 5));
+    /// assert_eq!(Err(ParseError::Underflow), generator.try_sub("5", 6));
+    /// ```
+    pub fn try_sub<S: AsRef<str>>(&self, id: S, n: usize) -> Result<String, ParseError> {
+        let base = self.alphabet.len();
+        let position = self.try_position(id.as_ref())?;
+
+        let position = utils::sub_digits(&position, &utils::to_digits(n, base), base)
+            .ok_or(ParseError::Underflow)?;
+
+        self.render_position(&position)
+    }
+
+    /// Returns the id following `id`.
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let generator = LightId::new();
+    ///
+    /// assert_eq!("10", generator.succ("Z"));
+    /// ```
+    pub fn succ<S: AsRef<str>>(&self, id: S) -> String {
+        self.add(id, 1)
+    }
+
+    /// Returns the id following `id`, or the reason it cannot be computed.
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let generator = LightId::new();
+    ///
+    /// assert_eq!(Ok("10".to_string()), generator.try_succ("Z"));
+    /// ```
+    pub fn try_succ<S: AsRef<str>>(&self, id: S) -> Result<String, ParseError> {
+        self.try_add(id, 1)
+    }
+
+    /// Returns the id preceding `id`.
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let generator = LightId::new();
+    ///
+    /// assert_eq!("Z", generator.pred("10"));
+    /// ```
+    pub fn pred<S: AsRef<str>>(&self, id: S) -> String {
+        self.sub(id, 1)
+    }
+
+    /// Returns the id preceding `id`, or the reason it cannot be computed.
+    /// ```
+    /// use light_id::{LightId, ParseError};
+    ///
+    /// let generator = LightId::new();
+    ///
+    /// assert_eq!(Err(ParseError::Underflow), generator.try_pred("0"));
+    /// ```
+    pub fn try_pred<S: AsRef<str>>(&self, id: S) -> Result<String, ParseError> {
+        self.try_sub(id, 1)
+    }
+
+    /// Returns the number of positions between two ids, in any order.
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let generator = LightId::from("0123456789");
+    ///
+    /// assert_eq!(500, generator.distance("999", "1499"));
+    /// assert_eq!(500, generator.distance("1499", "999"));
+    /// ```
+    pub fn distance<A: AsRef<str>, B: AsRef<str>>(&self, a: A, b: B) -> usize {
+        self.try_distance(a, b).expect("Invalid id")
+    }
+
+    /// Returns the number of positions between two ids, or the reason it cannot be computed.
+    /// ```
+    /// use light_id::{LightId, ParseError};
+    ///
+    /// let generator = LightId::from("0123456789");
+    ///
+    /// assert_eq!(Ok(1), generator.try_distance("99999999999999999999999", "100000000000000000000000"));
+    /// assert_eq!(Err(ParseError::Overflow), generator.try_distance("0", "99999999999999999999999"));
+    /// ```
+    pub fn try_distance<A: AsRef<str>, B: AsRef<str>>(
+        &self,
+        a: A,
+        b: B,
+    ) -> Result<usize, ParseError> {
+        let base = self.alphabet.len();
+        let (a, b) = (self.try_position(a.as_ref())?, self.try_position(b.as_ref())?);
+
+        let difference = match utils::compare_digits(&a, &b) {
+            Ordering::Less => utils::sub_digits(&b, &a, base),
+            _ => utils::sub_digits(&a, &b, base),
+        };
+
+        utils::from_digits(&difference.unwrap_or_default(), base)
+    }
+
+    /// Returns the id coming first in the sequence.
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let generator = LightId::from("0123456789");
+    ///
+    /// assert_eq!("9", generator.min_id("10", "9"));
+    /// ```
+    pub fn min_id<A: AsRef<str>, B: AsRef<str>>(&self, a: A, b: B) -> String {
+        match self.compare_ids(&a, &b) {
+            Ordering::Greater => b.as_ref().to_string(),
+            _ => a.as_ref().to_string(),
+        }
+    }
+
+    /// Returns the id coming last in the sequence.
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let generator = LightId::from("0123456789");
+    ///
+    /// assert_eq!("10", generator.max_id("10", "9"));
+    /// ```
+    pub fn max_id<A: AsRef<str>, B: AsRef<str>>(&self, a: A, b: B) -> String {
+        match self.compare_ids(&a, &b) {
+            Ordering::Less => b.as_ref().to_string(),
+            _ => a.as_ref().to_string(),
+        }
+    }
+
+    /// Returns the digits of the position of an id in the sequence.
+    pub(crate) fn try_position(&self, id: &str) -> Result<Vec<usize>, ParseError> {
+        let base = self.alphabet.len();
+
+        if let Some(n) = self.epochs.iter().find_map(|epoch| epoch.issued(id)) {
+            return Ok(utils::to_digits(n, base));
+        }
+
+        let mut value = self.parse_value(id)?;
+
+        if self.descending {
+            value = self.complement(&value);
+        }
+
+        let delta = utils::sub_digits(&value, &utils::to_digits(self.offset, base), base)
+            .ok_or(ParseError::OutOfSequence)?;
+
+        let position = match utils::div_digits(&delta, self.step, base) {
+            (position, 0) => position,
+            _ => return Err(ParseError::OutOfSequence),
+        };
+
+        // Positions skipped by migrations or owned by a previous epoch
+        let position = utils::sub_digits(&position, &utils::to_digits(self.shift, base), base)
+            .ok_or(ParseError::OutOfSequence)?;
+        let end = self.epochs.last().map_or(0, |epoch| epoch.end());
+
+        match utils::compare_digits(&position, &utils::to_digits(end, base)) {
+            Ordering::Less => Err(ParseError::OutOfSequence),
+            _ => Ok(position),
+        }
+    }
+
+    /// Returns the id at a position made of digits.
+    fn render_position(&self, position: &[usize]) -> Result<String, ParseError> {
+        let base = self.alphabet.len();
+
+        if let Ok(n) = utils::from_digits(position, base) {
+            if let Some(epoch) = self.epochs.iter().find(|epoch| epoch.owns(n)) {
+                return Ok(epoch.nth(n));
+            }
+        }
+
+        let position = utils::add_digits(position, &utils::to_digits(self.shift, base), base);
+        let value = utils::add_digits(
+            &utils::mul_digits(&position, self.step, base),
+            &utils::to_digits(self.offset, base),
+            base,
+        );
+
+        if self.max_length.is_some_and(|max| value.len() > max) {
+            return Err(ParseError::Overflow);
+        }
+
+        if self.descending {
+            return Ok(self.render_value(&self.complement(&value)));
+        }
+
+        Ok(self.render_value(&value))
+    }
+
+    /// Returns the digits of the largest value fitting the max length, minus `value`.
+    fn complement(&self, value: &[usize]) -> Vec<usize> {
+        let max = self.max_length.expect("Descending ids need a max length");
+        let base = self.alphabet.len();
+
+        let digits: Vec<usize> = std::iter::repeat_n(0, max.saturating_sub(value.len()))
+            .chain(value.iter().copied())
+            .map(|digit| base - 1 - digit)
+            .collect();
+
+        utils::trim_digits(&digits).to_vec()
+    }
+}
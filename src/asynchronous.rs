@@ -0,0 +1,235 @@
+use std::future::Future;
+use std::io;
+use std::ops::Range;
+use std::sync::Arc;
+
+use tokio::task::{self, JoinHandle};
+
+use crate::{CounterStore, LightId, MemoryStore};
+
+/// A [`CounterStore`] whose operations are asynchronous, requiring the `async` feature.
+pub trait AsyncCounterStore: Send + Sync {
+    /// Returns the current value of the counter.
+    fn load(&self) -> impl Future<Output = io::Result<usize>> + Send;
+
+    /// Sets the counter to `new` if it still equals `current`, returning whether it was set.
+    fn compare_and_swap(
+        &self,
+        current: usize,
+        new: usize,
+    ) -> impl Future<Output = io::Result<bool>> + Send;
+
+    /// Advances the counter by `n`, returning its previous value.
+    fn reserve(&self, n: usize) -> impl Future<Output = io::Result<usize>> + Send {
+        async move {
+            loop {
+                let current = self.load().await?;
+                let new = current
+                    .checked_add(n)
+                    .ok_or_else(|| io::Error::other("counter overflow"))?;
+
+                if self.compare_and_swap(current, new).await? {
+                    return Ok(current);
+                }
+            }
+        }
+    }
+}
+
+impl AsyncCounterStore for MemoryStore {
+    async fn load(&self) -> io::Result<usize> {
+        CounterStore::load(self)
+    }
+
+    async fn compare_and_swap(&self, current: usize, new: usize) -> io::Result<bool> {
+        CounterStore::compare_and_swap(self, current, new)
+    }
+
+    async fn reserve(&self, n: usize) -> io::Result<usize> {
+        CounterStore::reserve(self, n)
+    }
+}
+
+/// Runs the operations of a blocking [`CounterStore`], such as a
+/// [`FileStore`](crate::FileStore), on the blocking threads of the runtime.
+/// ```
+/// use light_id::{AsyncCounterStore, Blocking, FileStore};
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let dir = tempfile::tempdir().unwrap();
+/// let store = Blocking::new(FileStore::new(dir.path().join("orders.counter")));
+///
+/// assert_eq!(0, store.reserve(10).await.unwrap());
+/// assert_eq!(10, store.load().await.unwrap());
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct Blocking<S> {
+    store: Arc<S>,
+}
+
+impl<S> Blocking<S> {
+    /// Wraps a blocking [`CounterStore`].
+    pub fn new(store: S) -> Self {
+        Blocking {
+            store: Arc::new(store),
+        }
+    }
+}
+
+impl<S> Clone for Blocking<S> {
+    fn clone(&self) -> Self {
+        Blocking {
+            store: self.store.clone(),
+        }
+    }
+}
+
+impl<S> Blocking<S>
+where
+    S: CounterStore + Send + Sync + 'static,
+{
+    async fn run<T, F>(&self, f: F) -> io::Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&S) -> io::Result<T> + Send + 'static,
+    {
+        let store = self.store.clone();
+
+        task::spawn_blocking(move || f(&store))
+            .await
+            .map_err(io::Error::other)?
+    }
+}
+
+impl<S> AsyncCounterStore for Blocking<S>
+where
+    S: CounterStore + Send + Sync + 'static,
+{
+    async fn load(&self) -> io::Result<usize> {
+        self.run(|store| store.load()).await
+    }
+
+    async fn compare_and_swap(&self, current: usize, new: usize) -> io::Result<bool> {
+        self.run(move |store| store.compare_and_swap(current, new))
+            .await
+    }
+
+    async fn reserve(&self, n: usize) -> io::Result<usize> {
+        self.run(move |store| store.reserve(n)).await
+    }
+}
+
+/// A generator whose position is kept in an [`AsyncCounterStore`], reserving
+/// blocks of positions. The next block is fetched in the background once the
+/// current one runs low, so that [`AsyncLightId::next`] rarely waits on the store,
+/// even with the default block of a single position.
+///
+/// Positions reserved but not issued when the generator is dropped are skipped.
+/// Requires the `async` feature, and a tokio runtime to spawn the prefetching tasks.
+/// ```
+/// use light_id::{AsyncLightId, LightId, MemoryStore};
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let mut generator = AsyncLightId::new(LightId::new(), MemoryStore::new());
+///
+/// generator.block(100);
+///
+/// assert_eq!("0", generator.next().await.unwrap());
+/// assert_eq!("1", generator.next().await.unwrap());
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct AsyncLightId<S> {
+    generator: LightId,
+    store: Arc<S>,
+    block: usize,
+    threshold: usize,
+    reserved: Range<usize>,
+    prefetched: Option<JoinHandle<io::Result<usize>>>,
+}
+
+impl<S: AsyncCounterStore + 'static> AsyncLightId<S> {
+    /// Creates a new [`AsyncLightId`], formatting the ids with `generator`
+    /// and ignoring its own count.
+    pub fn new(generator: LightId, store: S) -> Self {
+        AsyncLightId {
+            generator,
+            store: Arc::new(store),
+            block: 1,
+            threshold: 0,
+            reserved: 0..0,
+            prefetched: None,
+        }
+    }
+
+    /// Sets the number of positions reserved at once, and starts fetching
+    /// the next block when half of the current one is used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn block(&mut self, size: usize) -> &mut Self {
+        assert!(size > 0, "The block size must be positive");
+
+        self.block = size;
+        self.threshold = size / 2;
+        self
+    }
+
+    /// Starts fetching the next block once `remaining` positions or less are
+    /// left in the current one. It defaults to zero, fetching the next block as
+    /// soon as the last position of the current one is issued.
+    /// ```
+    /// use light_id::{AsyncLightId, LightId, MemoryStore};
+    ///
+    /// let mut generator = AsyncLightId::new(LightId::new(), MemoryStore::new());
+    ///
+    /// generator.block(1000).prefetch(100);
+    /// ```
+    pub fn prefetch(&mut self, remaining: usize) -> &mut Self {
+        self.threshold = remaining;
+        self
+    }
+
+    /// Reserves the next position and returns its id, skipping the positions
+    /// whose id is blocked by the [`Blocklist`](crate::Blocklist) of the generator.
+    #[allow(clippy::should_implement_trait)]
+    pub async fn next(&mut self) -> io::Result<String> {
+        loop {
+            let n = match self.reserved.next() {
+                Some(n) => n,
+                None => {
+                    let start = match self.prefetched.take() {
+                        Some(handle) => handle.await.map_err(io::Error::other)??,
+                        None => self.store.reserve(self.block).await?,
+                    };
+
+                    self.reserved = start + 1..start + self.block;
+                    start
+                }
+            };
+
+            if self.prefetched.is_none() && self.reserved.len() <= self.threshold {
+                let store = self.store.clone();
+                let block = self.block;
+
+                self.prefetched = Some(task::spawn(async move { store.reserve(block).await }));
+            }
+
+            if !self.generator.is_blocked_at(n) {
+                return Ok(self.generator.nth(n));
+            }
+        }
+    }
+
+    /// Returns the generator formatting the ids.
+    pub fn generator(&self) -> &LightId {
+        &self.generator
+    }
+
+    /// Returns the store of the counter.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+}
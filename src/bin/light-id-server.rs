@@ -0,0 +1,58 @@
+use std::process::ExitCode;
+
+use light_id::TicketServer;
+
+const USAGE: &str = "\
+Usage: light-id-server [--listen ADDRESS] [--dir DIRECTORY]
+
+Serves block leases of the counters kept in DIRECTORY (default: counters),
+listening on ADDRESS (default: 127.0.0.1:7878).";
+
+fn run(args: Vec<String>) -> Result<(), String> {
+    let mut listen = "127.0.0.1:7878".to_string();
+    let mut directory = "counters".to_string();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let (name, value) = match arg.split_once('=') {
+            Some((name, value)) => (name.to_string(), Some(value.to_string())),
+            None => (arg, None),
+        };
+
+        let target = match name.as_str() {
+            "--listen" => &mut listen,
+            "--dir" => &mut directory,
+            "help" | "--help" | "-h" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ => return Err(format!("unknown argument {:?}\n\n{}", name, USAGE)),
+        };
+
+        *target = value
+            .or_else(|| args.next())
+            .ok_or(format!("missing value for {}", name))?;
+    }
+
+    let mut server = TicketServer::bind(&listen, &directory).map_err(|e| e.to_string())?;
+
+    server.on_error(|error| eprintln!("light-id-server: {}", error));
+
+    eprintln!(
+        "light-id-server: serving {} on {}",
+        directory,
+        server.local_addr().map_err(|e| e.to_string())?
+    );
+
+    server.serve().map_err(|e| e.to_string())
+}
+
+fn main() -> ExitCode {
+    match run(std::env::args().skip(1).collect()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("light-id-server: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use crate::Format;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Node {
+    next: BTreeMap<char, usize>,
+    fail: usize,
+    // Whether a substring ends here, directly or through the failure links
+    terminal: bool,
+}
+
+/// Ids that [`LightId::next`](crate::LightId::next) must skip: ids containing
+/// one of the blocked substrings, and blocked ids. Generators match it against
+/// the symbols of their ids, without the check symbol and the format.
+///
+/// Substrings are matched with an Aho-Corasick automaton, so that checking an
+/// id takes a time proportional to its length, whatever the size of the list.
+/// ```
+/// use light_id::Blocklist;
+///
+/// let mut blocklist = Blocklist::new();
+///
+/// blocklist.substrings(["fu", "xx"]).exact(["admin", "api"]);
+///
+/// assert!(blocklist.is_blocked("3fu9"));
+/// assert!(blocklist.is_blocked("api"));
+/// assert!(!blocklist.is_blocked("apis"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Blocklist {
+    substrings: BTreeSet<String>,
+    exact: BTreeSet<String>,
+    // The exact ids in lowercase, looked up when ignoring the case
+    folded: BTreeSet<String>,
+    case_insensitive: bool,
+    nodes: Vec<Node>,
+}
+
+impl Default for Blocklist {
+    fn default() -> Self {
+        Blocklist::new()
+    }
+}
+
+impl Blocklist {
+    /// Creates an empty [`Blocklist`].
+    /// ```
+    /// use light_id::Blocklist;
+    ///
+    /// assert!(Blocklist::new().is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Blocklist {
+            substrings: BTreeSet::new(),
+            exact: BTreeSet::new(),
+            folded: BTreeSet::new(),
+            case_insensitive: false,
+            nodes: vec![Node::default()],
+        }
+    }
+
+    /// Blocks the ids containing any of `substrings`.
+    /// ```
+    /// use light_id::Blocklist;
+    ///
+    /// let mut blocklist = Blocklist::new();
+    /// blocklist.substrings(["bad"]);
+    ///
+    /// assert!(blocklist.is_blocked("xbadx"));
+    /// ```
+    pub fn substrings<I, S>(&mut self, substrings: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.substrings.extend(
+            substrings
+                .into_iter()
+                .map(|s| s.as_ref().to_string())
+                .filter(|s| !s.is_empty()),
+        );
+        self.build();
+        self
+    }
+
+    /// Blocks the ids equal to any of `ids`.
+    /// ```
+    /// use light_id::Blocklist;
+    ///
+    /// let mut blocklist = Blocklist::new();
+    /// blocklist.exact(["www"]);
+    ///
+    /// assert!(blocklist.is_blocked("www"));
+    /// assert!(!blocklist.is_blocked("www2"));
+    /// ```
+    pub fn exact<I, S>(&mut self, ids: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for id in ids {
+            self.folded.insert(id.as_ref().to_lowercase());
+            self.exact.insert(id.as_ref().to_string());
+        }
+        self
+    }
+
+    /// Ignores the case when matching.
+    /// ```
+    /// use light_id::Blocklist;
+    ///
+    /// let mut blocklist = Blocklist::new();
+    /// blocklist.substrings(["bad"]).exact(["admin"]).case_insensitive(true);
+    ///
+    /// assert!(blocklist.is_blocked("xBaDx"));
+    /// assert!(blocklist.is_blocked("Admin"));
+    /// ```
+    pub fn case_insensitive(&mut self, enabled: bool) -> &mut Self {
+        self.case_insensitive = enabled;
+        self.build();
+        self
+    }
+
+    /// Returns whether nothing is blocked.
+    pub fn is_empty(&self) -> bool {
+        self.substrings.is_empty() && self.exact.is_empty()
+    }
+
+    /// Returns whether an id is blocked.
+    /// ```
+    /// use light_id::Blocklist;
+    ///
+    /// let mut blocklist = Blocklist::new();
+    /// blocklist.substrings(["he", "she", "hers"]);
+    ///
+    /// assert!(blocklist.is_blocked("ushers"));
+    /// assert!(!blocklist.is_blocked("hirs"));
+    /// ```
+    pub fn is_blocked<S: AsRef<str>>(&self, id: S) -> bool {
+        let id = id.as_ref();
+
+        if self.case_insensitive {
+            let id = id.to_lowercase();
+
+            self.folded.contains(&id) || self.contains_substring(&id)
+        } else {
+            self.exact.contains(id) || self.contains_substring(id)
+        }
+    }
+
+    /// Returns whether the prefix or the suffix of a format contains a blocked substring.
+    pub(crate) fn blocks_format(&self, format: &Format) -> bool {
+        [format.prefix_str(), format.suffix_str()]
+            .iter()
+            .any(|text| match self.case_insensitive {
+                true => self.contains_substring(&text.to_lowercase()),
+                false => self.contains_substring(text),
+            })
+    }
+
+    fn contains_substring(&self, id: &str) -> bool {
+        let mut state = 0;
+
+        for c in id.chars() {
+            state = self.step(state, c);
+
+            if self.nodes[state].terminal {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn step(&self, mut state: usize, c: char) -> usize {
+        loop {
+            if let Some(next) = self.nodes[state].next.get(&c) {
+                return *next;
+            }
+
+            if state == 0 {
+                return 0;
+            }
+
+            state = self.nodes[state].fail;
+        }
+    }
+
+    /// Rebuilds the automaton from the substrings.
+    fn build(&mut self) {
+        self.nodes = vec![Node::default()];
+
+        for substring in &self.substrings {
+            let substring = match self.case_insensitive {
+                true => substring.to_lowercase(),
+                false => substring.clone(),
+            };
+            let mut state = 0;
+
+            for c in substring.chars() {
+                state = match self.nodes[state].next.get(&c) {
+                    Some(next) => *next,
+                    None => {
+                        self.nodes.push(Node::default());
+
+                        let next = self.nodes.len() - 1;
+                        self.nodes[state].next.insert(c, next);
+                        next
+                    }
+                };
+            }
+
+            self.nodes[state].terminal = true;
+        }
+
+        let mut queue: VecDeque<usize> = self.nodes[0].next.values().copied().collect();
+
+        while let Some(state) = queue.pop_front() {
+            let children: Vec<(char, usize)> = self.nodes[state]
+                .next
+                .iter()
+                .map(|(c, n)| (*c, *n))
+                .collect();
+
+            for (c, child) in children {
+                let fail = match state {
+                    0 => 0,
+                    _ => self.step(self.nodes[state].fail, c),
+                };
+
+                self.nodes[child].fail = fail;
+                self.nodes[child].terminal |= self.nodes[fail].terminal;
+                queue.push_back(child);
+            }
+        }
+    }
+}
@@ -0,0 +1,362 @@
+use crate::{Alphabet, Blocklist, ConfigError, Format, LightId, DEFAULT_CHARACTERS};
+
+/// How [`LightIdBuilder::sortable`] handles alphabets that are not in code-point order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Sorting {
+    /// Fails with [`ConfigError::UnsortedAlphabet`].
+    Reject,
+    /// Puts the symbols in code-point order, changing their values.
+    Resort,
+}
+
+/// Collects the configuration of a [`LightId`], validating it on [`LightIdBuilder::build`].
+/// ```
+/// use light_id::{LightIdBuilder, Preset};
+///
+/// let mut generator = LightIdBuilder::new()
+///     .alphabet(Preset::Crockford)
+///     .min(7)
+///     .max(7)
+///     .start(1000)
+///     .checksum(true)
+///     .prefix("ord_")
+///     .group(4, "-")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!("ord_0000-0Z8H", generator.next());
+/// ```
+#[derive(Clone, Debug)]
+pub struct LightIdBuilder {
+    alphabet: Alphabet,
+    min_length: usize,
+    max_length: Option<usize>,
+    start: usize,
+    step: usize,
+    offset: usize,
+    descending: bool,
+    sorting: Option<Sorting>,
+    checksum: bool,
+    blocklist: Blocklist,
+    format: Format,
+}
+
+impl Default for LightIdBuilder {
+    fn default() -> Self {
+        LightIdBuilder::new()
+    }
+}
+
+impl LightIdBuilder {
+    /// Creates a new [`LightIdBuilder`] with the default configuration.
+    /// ```
+    /// use light_id::{LightId, LightIdBuilder};
+    ///
+    /// assert_eq!(LightId::new(), LightIdBuilder::new().build().unwrap());
+    /// ```
+    pub fn new() -> Self {
+        LightIdBuilder {
+            alphabet: Alphabet::new(DEFAULT_CHARACTERS),
+            min_length: 0,
+            max_length: None,
+            start: 0,
+            step: 1,
+            offset: 0,
+            descending: false,
+            sorting: None,
+            checksum: false,
+            blocklist: Blocklist::new(),
+            format: Format::new(),
+        }
+    }
+
+    /// Sets the alphabet of the ids.
+    /// ```
+    /// use light_id::LightIdBuilder;
+    ///
+    /// let generator = LightIdBuilder::new().alphabet("abc").build().unwrap();
+    ///
+    /// assert_eq!("a", generator.current());
+    /// ```
+    pub fn alphabet<A: Into<Alphabet>>(&mut self, alphabet: A) -> &mut Self {
+        self.alphabet = alphabet.into();
+        self
+    }
+
+    /// Sets the min length of the ids, as counted by [`LightId::min`].
+    /// ```
+    /// use light_id::LightIdBuilder;
+    ///
+    /// let generator = LightIdBuilder::new().min(3).build().unwrap();
+    ///
+    /// assert_eq!("000", generator.current());
+    /// ```
+    pub fn min(&mut self, n: usize) -> &mut Self {
+        self.min_length = n;
+        self
+    }
+
+    /// Sets the max length of the ids, as counted by [`LightId::max`].
+    /// ```
+    /// use light_id::LightIdBuilder;
+    ///
+    /// let generator = LightIdBuilder::new().alphabet("01").max(4).build().unwrap();
+    ///
+    /// assert_eq!(Some(16), generator.capacity());
+    /// ```
+    pub fn max(&mut self, n: usize) -> &mut Self {
+        self.max_length = Some(n);
+        self
+    }
+
+    /// Sets the position of the generator in the sequence, skipping the first ids.
+    /// ```
+    /// use light_id::LightIdBuilder;
+    ///
+    /// let generator = LightIdBuilder::new().start(10).build().unwrap();
+    ///
+    /// assert_eq!("a", generator.current());
+    /// ```
+    pub fn start(&mut self, n: usize) -> &mut Self {
+        self.start = n;
+        self
+    }
+
+    /// Sets the stride between two consecutive ids.
+    /// ```
+    /// use light_id::LightIdBuilder;
+    ///
+    /// let mut generator = LightIdBuilder::new().step(2).build().unwrap();
+    ///
+    /// assert_eq!("0", generator.next());
+    /// assert_eq!("2", generator.next());
+    /// ```
+    pub fn step(&mut self, n: usize) -> &mut Self {
+        self.step = n;
+        self
+    }
+
+    /// Sets the value of the first id.
+    /// ```
+    /// use light_id::LightIdBuilder;
+    ///
+    /// let mut generator = LightIdBuilder::new().step(2).offset(1).build().unwrap();
+    ///
+    /// assert_eq!("1", generator.next());
+    /// assert_eq!("3", generator.next());
+    /// ```
+    pub fn offset(&mut self, n: usize) -> &mut Self {
+        self.offset = n;
+        self
+    }
+
+    /// Emits the ids in reverse order, so that later ids sort first. Requires a max length.
+    /// ```
+    /// use light_id::LightIdBuilder;
+    ///
+    /// let mut generator = LightIdBuilder::new().alphabet("0123456789").max(2).descending(true).build().unwrap();
+    ///
+    /// assert_eq!("99", generator.next());
+    /// assert_eq!("98", generator.next());
+    /// ```
+    pub fn descending(&mut self, enabled: bool) -> &mut Self {
+        self.descending = enabled;
+        self
+    }
+
+    /// Makes the ids sort as strings in the order of their values, padding them
+    /// to the max length and checking the alphabet is in code-point order.
+    /// ```
+    /// use light_id::{ConfigError, LightIdBuilder, Preset, Sorting};
+    ///
+    /// let mut builder = LightIdBuilder::new();
+    /// builder.alphabet(Preset::Base62).max(4);
+    ///
+    /// assert_eq!(Err(ConfigError::UnsortedAlphabet), builder.sortable(Sorting::Reject).build());
+    ///
+    /// let generator = builder.sortable(Sorting::Resort).build().unwrap();
+    ///
+    /// assert!(generator.nth(9) < generator.nth(10));
+    /// assert!(generator.nth(35) < generator.nth(36));
+    /// ```
+    pub fn sortable(&mut self, sorting: Sorting) -> &mut Self {
+        self.sorting = Some(sorting);
+        self
+    }
+
+    /// Appends a check symbol to the ids.
+    /// ```
+    /// use light_id::LightIdBuilder;
+    ///
+    /// let generator = LightIdBuilder::new().alphabet("0123456789").checksum(true).build().unwrap();
+    ///
+    /// assert_eq!("00", generator.current());
+    /// ```
+    pub fn checksum(&mut self, enabled: bool) -> &mut Self {
+        self.checksum = enabled;
+        self
+    }
+
+    /// Skips the ids matching a [`Blocklist`].
+    /// ```
+    /// use light_id::{Blocklist, LightIdBuilder};
+    ///
+    /// let mut blocklist = Blocklist::new();
+    /// blocklist.exact(["0"]);
+    ///
+    /// let generator = LightIdBuilder::new().blocklist(blocklist).build().unwrap();
+    ///
+    /// assert_eq!("1", generator.current());
+    /// ```
+    pub fn blocklist(&mut self, blocklist: Blocklist) -> &mut Self {
+        self.blocklist = blocklist;
+        self
+    }
+
+    /// Sets the whole [`Format`] of the ids.
+    /// ```
+    /// use light_id::{Format, LightIdBuilder};
+    ///
+    /// let mut format = Format::new();
+    /// format.prefix("#");
+    ///
+    /// let generator = LightIdBuilder::new().format(format).build().unwrap();
+    ///
+    /// assert_eq!("#0", generator.current());
+    /// ```
+    pub fn format(&mut self, format: Format) -> &mut Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the prefix of the ids.
+    /// ```
+    /// use light_id::LightIdBuilder;
+    ///
+    /// let generator = LightIdBuilder::new().prefix("usr_").build().unwrap();
+    ///
+    /// assert_eq!("usr_0", generator.current());
+    /// ```
+    pub fn prefix<S: AsRef<str>>(&mut self, prefix: S) -> &mut Self {
+        self.format.prefix(prefix);
+        self
+    }
+
+    /// Sets the suffix of the ids.
+    /// ```
+    /// use light_id::LightIdBuilder;
+    ///
+    /// let generator = LightIdBuilder::new().suffix("!").build().unwrap();
+    ///
+    /// assert_eq!("0!", generator.current());
+    /// ```
+    pub fn suffix<S: AsRef<str>>(&mut self, suffix: S) -> &mut Self {
+        self.format.suffix(suffix);
+        self
+    }
+
+    /// Splits the ids in groups of `size` symbols, counted from the end of the id.
+    /// ```
+    /// use light_id::LightIdBuilder;
+    ///
+    /// let generator = LightIdBuilder::new().min(6).group(3, "-").build().unwrap();
+    ///
+    /// assert_eq!("000-000", generator.current());
+    /// ```
+    pub fn group<S: AsRef<str>>(&mut self, size: usize, separator: S) -> &mut Self {
+        self.format.group(size, separator);
+        self
+    }
+
+    /// Validates the configuration and creates the [`LightId`].
+    /// ```
+    /// use light_id::{ConfigError, LightIdBuilder};
+    ///
+    /// assert_eq!(
+    ///     Err(ConfigError::DuplicateSymbol("a".to_string())),
+    ///     LightIdBuilder::new().alphabet("aba").build()
+    /// );
+    /// assert_eq!(Err(ConfigError::InvalidLength), LightIdBuilder::new().min(4).max(2).build());
+    /// ```
+    pub fn build(&self) -> Result<LightId, ConfigError> {
+        let symbols = self.alphabet.symbols();
+
+        if symbols.len() < 2 {
+            return Err(ConfigError::AlphabetTooSmall);
+        }
+
+        for (index, symbol) in symbols.iter().enumerate() {
+            if symbols[..index].contains(symbol) {
+                return Err(ConfigError::DuplicateSymbol(symbol.clone()));
+            }
+        }
+
+        if let Some(separator) = self.format.group_separator() {
+            let conflicts = self
+                .alphabet
+                .split(separator)
+                .iter()
+                .any(|symbol| matches!(self.alphabet.position(symbol), Some(Some(_))));
+
+            if conflicts {
+                return Err(ConfigError::ConflictingSeparator);
+            }
+        }
+
+        if self.blocklist.blocks_format(&self.format) {
+            return Err(ConfigError::BlockedFormat);
+        }
+
+        if let Some(max) = self.max_length {
+            if max == 0 || self.min_length > max {
+                return Err(ConfigError::InvalidLength);
+            }
+        }
+
+        if self.step == 0 {
+            return Err(ConfigError::ZeroStep);
+        }
+
+        let mut alphabet = self.alphabet.clone();
+
+        if let Some(sorting) = self.sorting {
+            if sorting == Sorting::Resort {
+                alphabet.sort();
+            }
+
+            if !alphabet.is_sorted() {
+                return Err(ConfigError::UnsortedAlphabet);
+            }
+        }
+
+        let mut generator = LightId::from(alphabet);
+
+        generator.format = self.format.clone();
+        generator
+            .min(self.min_length)
+            .step(self.step)
+            .offset(self.offset)
+            .descending(self.descending)
+            .checksum(self.checksum)
+            .blocklist(self.blocklist.clone())
+            .skip(self.start);
+
+        if let Some(max) = self.max_length {
+            generator.max(max);
+
+            if self.sorting.is_some() {
+                generator.min(max);
+            }
+        }
+
+        if (self.descending || self.sorting.is_some()) && generator.capacity().is_none() {
+            return Err(ConfigError::Unbounded);
+        }
+
+        if generator.capacity().is_some_and(|capacity| self.start >= capacity) {
+            return Err(ConfigError::StartOutOfRange);
+        }
+
+        Ok(generator)
+    }
+}
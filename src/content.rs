@@ -0,0 +1,184 @@
+use std::io::{self, Read};
+
+use sha2::digest::Output;
+use sha2::{Digest, Sha256};
+
+use crate::Alphabet;
+
+/// Derives short ids from the SHA-256 hash of some content, so that the same
+/// content always gets the same id, such as when deduplicating uploads.
+///
+/// The symbols are drawn from the hash without bias, and a longer id of the
+/// same content always starts with the shorter one.
+/// ```
+/// use light_id::{ContentId, Preset};
+///
+/// let mut content = ContentId::new(Preset::Base58);
+///
+/// content.length(6);
+///
+/// let id = content.id("hello world");
+///
+/// assert_eq!(6, id.chars().count());
+/// assert_eq!(id, content.id(b"hello world"));
+/// assert_ne!(id, content.id("hello world!"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ContentId {
+    alphabet: Alphabet,
+    length: usize,
+}
+
+impl ContentId {
+    /// Creates a new [`ContentId`] emitting ids of 8 symbols of `alphabet`.
+    ///
+    /// Panics if the alphabet has less than 2 symbols.
+    /// ```
+    /// use light_id::ContentId;
+    ///
+    /// let content = ContentId::new("0123456789abcdef");
+    ///
+    /// assert_eq!("b94d27b9", content.id("hello world"));
+    /// ```
+    pub fn new<A: Into<Alphabet>>(alphabet: A) -> Self {
+        let alphabet = alphabet.into();
+
+        assert!(alphabet.len() >= 2, "The alphabet needs at least 2 symbols");
+
+        ContentId {
+            alphabet,
+            length: 8,
+        }
+    }
+
+    /// Sets the number of symbols of the ids.
+    /// ```
+    /// use light_id::ContentId;
+    ///
+    /// let mut content = ContentId::new("0123456789abcdef");
+    ///
+    /// content.length(4);
+    ///
+    /// assert_eq!("b94d", content.id("hello world"));
+    /// ```
+    pub fn length(&mut self, n: usize) -> &mut Self {
+        self.length = n;
+        self
+    }
+
+    /// Returns the id of some content.
+    /// ```
+    /// use light_id::ContentId;
+    ///
+    /// let content = ContentId::new("01");
+    ///
+    /// assert_eq!("10111001", content.id([104, 101, 108, 108, 111, 32, 119, 111, 114, 108, 100]));
+    /// ```
+    pub fn id<B: AsRef<[u8]>>(&self, content: B) -> String {
+        self.render(&self.symbols(&Sha256::digest(content), self.length))
+    }
+
+    /// Returns the id of the content read from `reader`, hashing it as it is
+    /// read rather than loading it in memory, such as for large files.
+    /// ```
+    /// use light_id::ContentId;
+    ///
+    /// let content = ContentId::new("0123456789abcdef");
+    ///
+    /// assert_eq!("b94d27b9", content.id_reader(&b"hello world"[..]).unwrap());
+    /// ```
+    pub fn id_reader<R: Read>(&self, mut reader: R) -> io::Result<String> {
+        let mut hasher = Sha256::new();
+
+        io::copy(&mut reader, &mut hasher)?;
+
+        Ok(self.render(&self.symbols(&hasher.finalize(), self.length)))
+    }
+
+    /// Returns the shortest id of some content, from the configured length,
+    /// for which `taken` is false. `taken` tells whether an id already belongs
+    /// to another content, such as by looking it up in a set or a database.
+    ///
+    /// As the ids only get longer, `taken` must not report the id of the same
+    /// content, or the search never ends.
+    /// ```
+    /// use std::collections::HashSet;
+    ///
+    /// use light_id::ContentId;
+    ///
+    /// let mut content = ContentId::new("0123456789abcdef");
+    ///
+    /// content.length(2);
+    ///
+    /// let ids = HashSet::from(["b9".to_string(), "b94".to_string()]);
+    ///
+    /// assert_eq!("b94d", content.unique("hello world", |id| ids.contains(id)));
+    /// assert_eq!("d9", content.unique("other", |id| ids.contains(id)));
+    /// ```
+    pub fn unique<B, F>(&self, content: B, mut taken: F) -> String
+    where
+        B: AsRef<[u8]>,
+        F: FnMut(&str) -> bool,
+    {
+        let digest = Sha256::digest(content);
+        let mut length = self.length;
+
+        loop {
+            let id = self.render(&self.symbols(&digest, length));
+
+            if !taken(&id) {
+                return id;
+            }
+
+            length += 1;
+        }
+    }
+
+    /// Returns the first `length` symbols drawn from the bits of the hash of
+    /// the content. Values outside of the alphabet are rejected, and the hash is
+    /// stretched by hashing it with a block counter when more bits are needed.
+    fn symbols(&self, digest: &Output<Sha256>, length: usize) -> Vec<usize> {
+        let base = self.alphabet.len();
+        let bits = usize::BITS - (base - 1).leading_zeros();
+        let mut block = *digest;
+        let mut counter: u64 = 0;
+        let mut position = 0;
+        let mut symbols = vec![];
+
+        while symbols.len() < length {
+            let mut value = 0;
+
+            for _ in 0..bits {
+                if position == 256 {
+                    counter += 1;
+                    block = Sha256::new()
+                        .chain_update(digest)
+                        .chain_update(counter.to_be_bytes())
+                        .finalize();
+                    position = 0;
+                }
+
+                let bit = (block[position / 8] >> (7 - position % 8)) & 1;
+
+                value = value << 1 | bit as usize;
+                position += 1;
+            }
+
+            if value < base {
+                symbols.push(value);
+            }
+        }
+
+        symbols
+    }
+
+    fn render(&self, symbols: &[usize]) -> String {
+        let alphabet = self.alphabet.symbols();
+
+        symbols
+            .iter()
+            .map(|symbol| alphabet[*symbol].as_str())
+            .collect::<Vec<_>>()
+            .join(self.alphabet.separator())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::LightId;
+
+/// A configuration retired by [`LightId::migrate`], along with the range of
+/// indexes it was used for.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd)]
+pub(crate) struct Epoch {
+    generator: LightId,
+    start: usize,
+    end: usize,
+}
+
+impl Epoch {
+    pub(crate) fn new(generator: LightId, start: usize, end: usize) -> Self {
+        Epoch {
+            generator,
+            start,
+            end,
+        }
+    }
+
+    pub(crate) fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns whether the nth id was issued during this epoch.
+    pub(crate) fn owns(&self, n: usize) -> bool {
+        (self.start..self.end).contains(&n)
+    }
+
+    pub(crate) fn nth(&self, n: usize) -> String {
+        self.generator.nth(n)
+    }
+
+    pub(crate) fn is_blocked_at(&self, n: usize) -> bool {
+        self.generator.is_blocked_at(n)
+    }
+
+    /// Returns the length in bytes of the longest id issued during this epoch,
+    /// or 0 if none was.
+    pub(crate) fn longest(&self) -> usize {
+        match self.end > self.start {
+            true => self.generator.longest(self.end - 1),
+            false => 0,
+        }
+    }
+
+    /// Returns the index of `id` if it was issued during this epoch, comparing
+    /// the symbols rather than the strings so that case folding and aliases apply.
+    pub(crate) fn issued(&self, id: &str) -> Option<usize> {
+        let n = self.generator.try_index(id).ok().filter(|n| self.owns(*n))?;
+        let issued = self.generator.parse_value(&self.generator.nth(n));
+
+        (self.generator.parse_value(id) == issued).then_some(n)
+    }
+}
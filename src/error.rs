@@ -0,0 +1,110 @@
+use std::fmt;
+
+/// The reasons an id can fail to parse.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The id contains a character missing from the alphabet.
+    InvalidCharacter(char),
+    /// The id contains a symbol missing from the alphabet, made of several
+    /// characters (such as a word or a grapheme cluster).
+    InvalidSymbol(String),
+    /// The id lacks the expected prefix, suffix or literals.
+    InvalidFormat,
+    /// The id does not have the expected number of symbols.
+    InvalidLength,
+    /// The id starts with a prefix that no generator is registered for.
+    UnknownPrefix,
+    /// The check symbol of the id does not match.
+    InvalidChecksum,
+    /// The id is too large to be represented by a `usize`.
+    Overflow,
+    /// The id is not part of the sequence, given the step and offset of the generator.
+    OutOfSequence,
+    /// The id would come before the first id of the sequence.
+    Underflow,
+    /// The ids are not in increasing order.
+    Unordered,
+    /// The number of partitions or shards is zero.
+    ZeroPartitions,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidCharacter(c) => write!(f, "invalid character {:?}", c),
+            ParseError::InvalidSymbol(s) => write!(f, "invalid symbol {:?}", s),
+            ParseError::InvalidFormat => write!(f, "invalid format"),
+            ParseError::InvalidLength => write!(f, "invalid length"),
+            ParseError::UnknownPrefix => write!(f, "unknown prefix"),
+            ParseError::InvalidChecksum => write!(f, "invalid check symbol"),
+            ParseError::Overflow => write!(f, "id too large"),
+            ParseError::OutOfSequence => write!(f, "id not part of the sequence"),
+            ParseError::Underflow => write!(f, "id before the start of the sequence"),
+            ParseError::Unordered => write!(f, "ids not in increasing order"),
+            ParseError::ZeroPartitions => write!(f, "the number of partitions cannot be zero"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    /// Returns the error of a symbol missing from the alphabet, keeping
+    /// [`ParseError::InvalidCharacter`] for single characters.
+    pub(crate) fn invalid_symbol(symbol: &str) -> Self {
+        let mut chars = symbol.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => ParseError::InvalidCharacter(c),
+            _ => ParseError::InvalidSymbol(symbol.to_string()),
+        }
+    }
+}
+
+/// The reasons a configuration can be rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The alphabet has less than two symbols.
+    AlphabetTooSmall,
+    /// The alphabet contains the same symbol twice.
+    DuplicateSymbol(String),
+    /// The group separator contains symbols of the alphabet.
+    ConflictingSeparator,
+    /// The max length is zero or lower than the min length.
+    InvalidLength,
+    /// The start position exceeds the capacity.
+    StartOutOfRange,
+    /// The step is zero.
+    ZeroStep,
+    /// Descending or sortable ids are requested without a max length bounding them.
+    Unbounded,
+    /// Sortable ids are requested with an alphabet not in code-point order.
+    UnsortedAlphabet,
+    /// The template cannot be parsed.
+    InvalidTemplate(String),
+    /// The prefix or the suffix contains a substring of the blocklist.
+    BlockedFormat,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::AlphabetTooSmall => write!(f, "the alphabet needs at least 2 symbols"),
+            ConfigError::DuplicateSymbol(s) => write!(f, "duplicate symbol {:?}", s),
+            ConfigError::ConflictingSeparator => {
+                write!(f, "the group separator contains symbols of the alphabet")
+            }
+            ConfigError::InvalidLength => write!(f, "invalid min or max length"),
+            ConfigError::StartOutOfRange => write!(f, "the start position exceeds the capacity"),
+            ConfigError::ZeroStep => write!(f, "the step cannot be zero"),
+            ConfigError::Unbounded => {
+                write!(f, "the ids need a max length fitting in usize")
+            }
+            ConfigError::UnsortedAlphabet => write!(f, "the alphabet is not in code-point order"),
+            ConfigError::InvalidTemplate(message) => write!(f, "invalid template: {}", message),
+            ConfigError::BlockedFormat => write!(f, "the prefix or suffix contains a blocked word"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
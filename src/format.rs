@@ -0,0 +1,175 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Human-readable decorations applied around the ids: a prefix, a suffix and
+/// groups of characters joined by a separator.
+/// ```
+/// use light_id::LightId;
+///
+/// let mut generator = LightId::new();
+///
+/// generator.min(8);
+/// generator.format.prefix("ord_").group(4, "-");
+///
+/// assert_eq!("ord_0000-0000", generator.current());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Format {
+    prefix: String,
+    suffix: String,
+    group: Option<(usize, String)>,
+}
+
+impl Format {
+    /// Creates a new [`Format`], leaving the ids untouched.
+    /// ```
+    /// use light_id::Format;
+    ///
+    /// let format = Format::new();
+    ///
+    /// assert_eq!("1a2b", format.apply("1a2b"));
+    /// ```
+    pub fn new() -> Self {
+        Format::default()
+    }
+
+    /// Sets the prefix of the ids.
+    /// ```
+    /// use light_id::Format;
+    ///
+    /// let mut format = Format::new();
+    /// format.prefix("usr_");
+    ///
+    /// assert_eq!("usr_1a2b", format.apply("1a2b"));
+    /// ```
+    pub fn prefix<S: AsRef<str>>(&mut self, prefix: S) -> &mut Self {
+        self.prefix = prefix.as_ref().to_string();
+        self
+    }
+
+    /// Sets the suffix of the ids.
+    /// ```
+    /// use light_id::Format;
+    ///
+    /// let mut format = Format::new();
+    /// format.suffix(".png");
+    ///
+    /// assert_eq!("1a2b.png", format.apply("1a2b"));
+    /// ```
+    pub fn suffix<S: AsRef<str>>(&mut self, suffix: S) -> &mut Self {
+        self.suffix = suffix.as_ref().to_string();
+        self
+    }
+
+    /// Splits the ids in groups of `size` symbols, joined by `separator`.
+    /// The groups are counted from the end of the id, like digit grouping.
+    /// A `size` of `0` disables grouping.
+    /// ```
+    /// use light_id::Format;
+    ///
+    /// let mut format = Format::new();
+    /// format.group(3, " ");
+    ///
+    /// assert_eq!("1 234 567", format.apply("1234567"));
+    /// ```
+    pub fn group<S: AsRef<str>>(&mut self, size: usize, separator: S) -> &mut Self {
+        self.group = match size {
+            0 => None,
+            _ => Some((size, separator.as_ref().to_string())),
+        };
+        self
+    }
+
+    pub(crate) fn prefix_str(&self) -> &str {
+        &self.prefix
+    }
+
+    pub(crate) fn suffix_str(&self) -> &str {
+        &self.suffix
+    }
+
+    pub(crate) fn group_separator(&self) -> Option<&str> {
+        self.group.as_ref().map(|(_, separator)| separator.as_str())
+    }
+
+    /// Decorates a raw id.
+    /// ```
+    /// use light_id::Format;
+    ///
+    /// let mut format = Format::new();
+    /// format.prefix("#").group(2, ":");
+    ///
+    /// assert_eq!("#ab:cd", format.apply("abcd"));
+    /// ```
+    pub fn apply(&self, id: &str) -> String {
+        self.render(&id.graphemes(true).collect::<Vec<_>>(), "")
+    }
+
+    /// Decorates the symbols of an id, joined by `joiner` within a group.
+    pub(crate) fn render(&self, symbols: &[&str], joiner: &str) -> String {
+        let mut formatted = self.prefix.clone();
+
+        match &self.group {
+            Some((size, separator)) => {
+                let first = match symbols.len() % size {
+                    0 => *size,
+                    n => n,
+                };
+
+                for (index, symbol) in symbols.iter().enumerate() {
+                    if index >= first && (index - first) % size == 0 {
+                        formatted.push_str(separator);
+                    } else if index > 0 {
+                        formatted.push_str(joiner);
+                    }
+                    formatted.push_str(symbol);
+                }
+            }
+            None => formatted.push_str(&symbols.join(joiner)),
+        }
+
+        formatted.push_str(&self.suffix);
+        formatted
+    }
+
+    /// Removes the decorations of an id, returning the raw id.
+    /// Returns `None` if the prefix or the suffix is missing, or if the group
+    /// separators are not where [`Format::apply`] puts them.
+    /// ```
+    /// use light_id::Format;
+    ///
+    /// let mut format = Format::new();
+    /// format.prefix("ord_").group(4, "-");
+    ///
+    /// assert_eq!(Some("1a2b3c4d".to_string()), format.strip("ord_1a2b-3c4d"));
+    /// assert_eq!(None, format.strip("1a2b-3c4d"));
+    /// assert_eq!(None, format.strip("ord_1a-2b3c4d"));
+    /// ```
+    pub fn strip(&self, id: &str) -> Option<String> {
+        self.strip_with(id, "")
+    }
+
+    /// Removes the decorations of an id, replacing the group separators by `joiner`.
+    pub(crate) fn strip_with(&self, id: &str, joiner: &str) -> Option<String> {
+        let raw = id
+            .strip_prefix(self.prefix.as_str())?
+            .strip_suffix(self.suffix.as_str())?;
+
+        let (size, separator) = match &self.group {
+            Some((size, separator)) if !separator.is_empty() => (*size, separator),
+            _ => return Some(raw.to_string()),
+        };
+
+        let groups: Vec<&str> = raw.split(separator.as_str()).collect();
+        let symbols = |group: &str| match joiner {
+            _ if group.is_empty() => 0,
+            "" => group.graphemes(true).count(),
+            joiner => group.split(joiner).count(),
+        };
+
+        // Only the first group can be shorter, as they are counted from the end
+        let valid = (1..=size).contains(&symbols(groups[0]))
+            && groups[1..].iter().all(|group| symbols(group) == size);
+
+        valid.then(|| groups.join(joiner))
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{utils, Alphabet, ParseError};
+
+/// Generates LexoRank-style keys for user-ordered lists: a key can always be
+/// made to sort strictly between two others, growing only when needed.
+///
+/// Keys are read as fractions in the base of the alphabet (`"8"` is `0.8` in
+/// base 10), so they never end with the first symbol of the alphabet.
+/// ```
+/// use light_id::{FractionalIndex, Preset};
+///
+/// let index = FractionalIndex::new(Preset::Base10);
+///
+/// let first = index.first();
+/// let last = index.after(&first);
+/// let middle = index.between(&first, &last);
+///
+/// assert_eq!(("5", "8", "7"), (first.as_str(), last.as_str(), middle.as_str()));
+/// assert!(first < middle && middle < last);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FractionalIndex {
+    alphabet: Alphabet,
+}
+
+impl FractionalIndex {
+    /// Creates a new [`FractionalIndex`] using the provided alphabet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alphabet is not in code-point order (see [`Alphabet::is_sorted`]),
+    /// as the keys would not sort as strings.
+    /// ```
+    /// use light_id::{FractionalIndex, Preset};
+    ///
+    /// let index = FractionalIndex::new(Preset::Base58);
+    /// ```
+    pub fn new<A: Into<Alphabet>>(alphabet: A) -> Self {
+        let alphabet = alphabet.into();
+
+        assert!(alphabet.len() > 1, "The alphabet needs at least 2 symbols");
+        assert!(alphabet.is_sorted(), "The alphabet must be in code-point order");
+
+        FractionalIndex { alphabet }
+    }
+
+    /// Returns the key of the first item of an empty list.
+    /// ```
+    /// use light_id::{FractionalIndex, Preset};
+    ///
+    /// assert_eq!("W", FractionalIndex::new(Preset::Base58).first());
+    /// ```
+    pub fn first(&self) -> String {
+        self.render(&self.midpoint(&[], None))
+    }
+
+    /// Returns a key sorting before `id`.
+    /// ```
+    /// use light_id::{FractionalIndex, Preset};
+    ///
+    /// let index = FractionalIndex::new(Preset::Base10);
+    ///
+    /// assert_eq!("3", index.before("6"));
+    /// assert_eq!("05", index.before("1"));
+    /// ```
+    pub fn before<S: AsRef<str>>(&self, id: S) -> String {
+        self.try_before(id).expect("Invalid id")
+    }
+
+    /// Returns a key sorting before `id`, or the reason `id` cannot be parsed.
+    /// ```
+    /// use light_id::{FractionalIndex, ParseError, Preset};
+    ///
+    /// let index = FractionalIndex::new(Preset::Base10);
+    ///
+    /// assert_eq!(Ok("3".to_string()), index.try_before("6"));
+    /// assert_eq!(Err(ParseError::InvalidFormat), index.try_before("60"));
+    /// ```
+    pub fn try_before<S: AsRef<str>>(&self, id: S) -> Result<String, ParseError> {
+        let digits = self.parse(id.as_ref())?;
+
+        Ok(self.render(&self.midpoint(&[], Some(&digits))))
+    }
+
+    /// Returns a key sorting after `id`.
+    /// ```
+    /// use light_id::{FractionalIndex, Preset};
+    ///
+    /// let index = FractionalIndex::new(Preset::Base10);
+    ///
+    /// assert_eq!("8", index.after("6"));
+    /// assert_eq!("95", index.after("9"));
+    /// ```
+    pub fn after<S: AsRef<str>>(&self, id: S) -> String {
+        self.try_after(id).expect("Invalid id")
+    }
+
+    /// Returns a key sorting after `id`, or the reason `id` cannot be parsed.
+    /// ```
+    /// use light_id::{FractionalIndex, ParseError, Preset};
+    ///
+    /// let index = FractionalIndex::new(Preset::Base10);
+    ///
+    /// assert_eq!(Ok("8".to_string()), index.try_after("6"));
+    /// assert_eq!(Err(ParseError::InvalidLength), index.try_after(""));
+    /// ```
+    pub fn try_after<S: AsRef<str>>(&self, id: S) -> Result<String, ParseError> {
+        let digits = self.parse(id.as_ref())?;
+
+        Ok(self.render(&self.midpoint(&digits, None)))
+    }
+
+    /// Returns a key sorting strictly between `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either id is invalid, or if `a` does not sort before `b`.
+    /// ```
+    /// use light_id::{FractionalIndex, Preset};
+    ///
+    /// let index = FractionalIndex::new(Preset::Base10);
+    ///
+    /// assert_eq!("4", index.between("2", "6"));
+    /// assert_eq!("25", index.between("2", "3"));
+    /// assert_eq!("201", index.between("2", "202"));
+    /// ```
+    pub fn between<A: AsRef<str>, B: AsRef<str>>(&self, a: A, b: B) -> String {
+        self.try_between(a, b).expect("Invalid id")
+    }
+
+    /// Returns a key sorting strictly between `a` and `b`, or the reason one of
+    /// them cannot be parsed. Fails with [`ParseError::Unordered`] if `a` does
+    /// not sort before `b`.
+    /// ```
+    /// use light_id::{FractionalIndex, ParseError, Preset};
+    ///
+    /// let index = FractionalIndex::new(Preset::Base10);
+    ///
+    /// assert_eq!(Ok("4".to_string()), index.try_between("2", "6"));
+    /// assert_eq!(Err(ParseError::InvalidCharacter('x')), index.try_between("2", "x"));
+    /// assert_eq!(Err(ParseError::Unordered), index.try_between("6", "2"));
+    /// ```
+    pub fn try_between<A: AsRef<str>, B: AsRef<str>>(
+        &self,
+        a: A,
+        b: B,
+    ) -> Result<String, ParseError> {
+        let a = self.parse(a.as_ref())?;
+        let b = self.parse(b.as_ref())?;
+
+        if a >= b {
+            return Err(ParseError::Unordered);
+        }
+
+        Ok(self.render(&self.midpoint(&a, Some(&b))))
+    }
+
+    /// Returns the digits of a key, rejecting the keys no other can follow.
+    fn parse(&self, id: &str) -> Result<Vec<usize>, ParseError> {
+        let digits = utils::parse_digits(id, &self.alphabet)?;
+
+        match digits.last() {
+            None => Err(ParseError::InvalidLength),
+            Some(0) => Err(ParseError::InvalidFormat),
+            Some(_) => Ok(digits),
+        }
+    }
+
+    fn render(&self, digits: &[usize]) -> String {
+        let symbols = self.alphabet.symbols();
+
+        digits
+            .iter()
+            .map(|digit| symbols[*digit].as_str())
+            .collect::<Vec<_>>()
+            .join(self.alphabet.separator())
+    }
+
+    /// Returns the shortest digits strictly between `a` and `b`, read as
+    /// fractions, `None` standing for one.
+    fn midpoint(&self, a: &[usize], b: Option<&[usize]>) -> Vec<usize> {
+        let base = self.alphabet.len();
+
+        if let Some(b) = b {
+            let shared = b
+                .iter()
+                .zip(a.iter().chain(std::iter::repeat(&0)))
+                .take_while(|(x, y)| x == y)
+                .count();
+
+            if shared > 0 {
+                let mut digits = b[..shared].to_vec();
+                digits.extend(self.midpoint(a.get(shared..).unwrap_or(&[]), Some(&b[shared..])));
+                return digits;
+            }
+        }
+
+        let low = a.first().copied().unwrap_or(0);
+        let high = b.map_or(base, |b| b[0]);
+
+        if high - low > 1 {
+            return vec![(low + high).div_ceil(2)];
+        }
+
+        match b {
+            Some(b) if b.len() > 1 => vec![b[0]],
+            _ => {
+                let mut digits = vec![low];
+                digits.extend(self.midpoint(a.get(1..).unwrap_or(&[]), None));
+                digits
+            }
+        }
+    }
+}
@@ -0,0 +1,421 @@
+use std::collections::BTreeMap;
+
+use crate::{LightId, ParseError};
+
+/// Generates dotted path ids such as `3.1a.7`, each node of the tree spawning
+/// its own scope of child ids.
+///
+/// Each segment is an id of the generator of its depth, the last generator
+/// being reused for the deeper levels. The separator must not contain symbols
+/// of the alphabets, nor the group separators of their formats.
+/// ```
+/// use light_id::{Hierarchy, LightId};
+///
+/// let mut hierarchy = Hierarchy::new(LightId::new());
+///
+/// let section = hierarchy.spawn("");
+/// let paragraph = hierarchy.spawn(&section);
+///
+/// assert_eq!("0", section);
+/// assert_eq!("0.0", paragraph);
+/// assert_eq!("0.1", hierarchy.spawn(&section));
+/// assert_eq!("1", hierarchy.spawn(""));
+///
+/// assert_eq!(vec![3, 72, 7], hierarchy.parse("3.1a.7"));
+/// assert_eq!("3.1a.7", hierarchy.path(&[3, 72, 7]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Hierarchy {
+    levels: Vec<LightId>,
+    separator: String,
+    // Number of children spawned under each node, keyed by the path of the node
+    children: BTreeMap<Vec<usize>, usize>,
+}
+
+impl Hierarchy {
+    /// Creates a new [`Hierarchy`], using `generator` for every depth and `.` as separator.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let hierarchy = Hierarchy::new(LightId::from("abc"));
+    ///
+    /// assert_eq!("b.c", hierarchy.path(&[1, 2]));
+    /// ```
+    pub fn new(generator: LightId) -> Self {
+        Hierarchy {
+            levels: vec![generator],
+            separator: ".".to_string(),
+            children: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a depth using `generator`, which is then reused for the deeper levels.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let mut hierarchy = Hierarchy::new(LightId::from("0123456789"));
+    ///
+    /// hierarchy.level(LightId::from("abcdefghij"));
+    ///
+    /// assert_eq!("3.b.h", hierarchy.path(&[3, 1, 7]));
+    /// ```
+    pub fn level(&mut self, generator: LightId) -> &mut Self {
+        self.levels.push(generator);
+        self
+    }
+
+    /// Sets the separator between the segments of the paths.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let mut hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// hierarchy.separator("/");
+    ///
+    /// assert_eq!("3/1a/7", hierarchy.path(&[3, 72, 7]));
+    /// ```
+    pub fn separator<S: AsRef<str>>(&mut self, separator: S) -> &mut Self {
+        self.separator = separator.as_ref().to_string();
+        self
+    }
+
+    /// Returns the generator of the segments at `depth`, the root children being at depth 0.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let mut hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// hierarchy.level(LightId::from("ab"));
+    ///
+    /// assert_eq!(&LightId::new(), hierarchy.generator(0));
+    /// assert_eq!(&LightId::from("ab"), hierarchy.generator(5));
+    /// ```
+    pub fn generator(&self, depth: usize) -> &LightId {
+        &self.levels[depth.min(self.levels.len() - 1)]
+    }
+
+    /// Returns the path made of the segment indices, the root being the empty path.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// assert_eq!("", hierarchy.path(&[]));
+    /// assert_eq!("a.0", hierarchy.path(&[10, 0]));
+    /// ```
+    pub fn path(&self, indices: &[usize]) -> String {
+        indices
+            .iter()
+            .enumerate()
+            .map(|(depth, n)| self.generator(depth).nth(*n))
+            .collect::<Vec<_>>()
+            .join(&self.separator)
+    }
+
+    /// Returns the index of each segment of a path.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// assert_eq!(vec![3, 72, 7], hierarchy.parse("3.1a.7"));
+    /// ```
+    pub fn parse<S: AsRef<str>>(&self, path: S) -> Vec<usize> {
+        self.try_parse(path).expect("Invalid id")
+    }
+
+    /// Returns the index of each segment of a path, or the reason it cannot be parsed.
+    /// ```
+    /// use light_id::{Hierarchy, LightId, ParseError};
+    ///
+    /// let hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// assert_eq!(Ok(vec![]), hierarchy.try_parse(""));
+    /// assert_eq!(Err(ParseError::InvalidFormat), hierarchy.try_parse("3..7"));
+    /// assert_eq!(Err(ParseError::InvalidCharacter('-')), hierarchy.try_parse("3.-"));
+    /// ```
+    pub fn try_parse<S: AsRef<str>>(&self, path: S) -> Result<Vec<usize>, ParseError> {
+        let path = path.as_ref();
+
+        if path.is_empty() {
+            return Ok(vec![]);
+        }
+
+        path.split(self.separator.as_str())
+            .enumerate()
+            .map(|(depth, segment)| match segment.is_empty() {
+                true => Err(ParseError::InvalidFormat),
+                false => self.generator(depth).try_index(segment),
+            })
+            .collect()
+    }
+
+    /// Returns the path of the parent of a node, or `None` for the root.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// assert_eq!(Some("3.1a".to_string()), hierarchy.parent("3.1a.7"));
+    /// assert_eq!(Some("".to_string()), hierarchy.parent("3"));
+    /// assert_eq!(None, hierarchy.parent(""));
+    /// ```
+    pub fn parent<S: AsRef<str>>(&self, path: S) -> Option<String> {
+        self.try_parent(path).expect("Invalid id")
+    }
+
+    /// Returns the path of the parent of a node, or the reason it cannot be parsed.
+    /// ```
+    /// use light_id::{Hierarchy, LightId, ParseError};
+    ///
+    /// let hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// assert_eq!(Ok(Some("3".to_string())), hierarchy.try_parent("3.7"));
+    /// assert_eq!(Err(ParseError::InvalidFormat), hierarchy.try_parent("3."));
+    /// ```
+    pub fn try_parent<S: AsRef<str>>(&self, path: S) -> Result<Option<String>, ParseError> {
+        let mut indices = self.try_parse(path)?;
+
+        Ok(indices.pop().map(|_| self.path(&indices)))
+    }
+
+    /// Returns the path of the first child of a node, the empty path being the root.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// assert_eq!("0", hierarchy.first_child(""));
+    /// assert_eq!("3.1a.7.0", hierarchy.first_child("3.1a.7"));
+    /// ```
+    pub fn first_child<S: AsRef<str>>(&self, path: S) -> String {
+        self.try_first_child(path).expect("Invalid id")
+    }
+
+    /// Returns the path of the first child of a node, or the reason it cannot be parsed.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// assert_eq!(Ok("1.0".to_string()), hierarchy.try_first_child("1"));
+    /// assert!(hierarchy.try_first_child("1.-").is_err());
+    /// ```
+    pub fn try_first_child<S: AsRef<str>>(&self, path: S) -> Result<String, ParseError> {
+        let mut indices = self.try_parse(path)?;
+
+        indices.push(0);
+
+        Ok(self.path(&indices))
+    }
+
+    /// Returns the path of the sibling following a node.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// assert_eq!("3.1a.8", hierarchy.next_sibling("3.1a.7"));
+    /// assert_eq!("3.1b", hierarchy.next_sibling("3.1a"));
+    /// ```
+    pub fn next_sibling<S: AsRef<str>>(&self, path: S) -> String {
+        self.try_next_sibling(path).expect("Invalid id")
+    }
+
+    /// Returns the path of the sibling following a node, or the reason it cannot be computed.
+    /// The root has no siblings, and fails with [`ParseError::InvalidLength`].
+    /// ```
+    /// use light_id::{Hierarchy, LightId, ParseError};
+    ///
+    /// let mut generator = LightId::from("01");
+    /// generator.max(1);
+    ///
+    /// let hierarchy = Hierarchy::new(generator);
+    ///
+    /// assert_eq!(Ok("0.1".to_string()), hierarchy.try_next_sibling("0.0"));
+    /// assert_eq!(Err(ParseError::Overflow), hierarchy.try_next_sibling("0.1"));
+    /// assert_eq!(Err(ParseError::InvalidLength), hierarchy.try_next_sibling(""));
+    /// ```
+    pub fn try_next_sibling<S: AsRef<str>>(&self, path: S) -> Result<String, ParseError> {
+        let mut indices = self.try_parse(path)?;
+        let depth = indices
+            .len()
+            .checked_sub(1)
+            .ok_or(ParseError::InvalidLength)?;
+        let next = indices[depth] + 1;
+
+        if self
+            .generator(depth)
+            .capacity()
+            .is_some_and(|capacity| next >= capacity)
+        {
+            return Err(ParseError::Overflow);
+        }
+
+        indices[depth] = next;
+
+        Ok(self.path(&indices))
+    }
+
+    /// Returns the scope of the children of a node, handing out the ones that
+    /// were not spawned yet. The children it hands out are recorded, so that
+    /// [`Hierarchy::spawn`] and other scopes of the node never repeat them.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let mut hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// hierarchy.spawn("3");
+    ///
+    /// let mut scope = hierarchy.scope("3");
+    ///
+    /// assert_eq!("3.1", scope.next());
+    /// assert_eq!("3.2", scope.next());
+    /// assert_eq!("3.3", hierarchy.spawn("3"));
+    /// ```
+    pub fn scope<S: AsRef<str>>(&mut self, path: S) -> ChildScope<'_> {
+        self.try_scope(path).expect("Invalid id")
+    }
+
+    /// Returns the scope of the children of a node, or the reason the path cannot be parsed.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let mut hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// assert_eq!("3.1a.0", hierarchy.try_scope("3.1a").unwrap().current());
+    /// assert!(hierarchy.try_scope("3..1a").is_err());
+    /// ```
+    pub fn try_scope<S: AsRef<str>>(&mut self, path: S) -> Result<ChildScope<'_>, ParseError> {
+        let node = self.try_parse(path)?;
+
+        Ok(ChildScope {
+            hierarchy: self,
+            node,
+        })
+    }
+
+    /// Spawns the next child of a node, returning its path.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let mut hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// assert_eq!("3.0", hierarchy.spawn("3"));
+    /// assert_eq!("3.1", hierarchy.spawn("3"));
+    /// assert_eq!("3.1.0", hierarchy.spawn("3.1"));
+    /// ```
+    pub fn spawn<S: AsRef<str>>(&mut self, path: S) -> String {
+        self.try_spawn(path).expect("Invalid id")
+    }
+
+    /// Spawns the next child of a node, or returns the reason the path cannot be parsed.
+    /// ```
+    /// use light_id::{Hierarchy, LightId, ParseError};
+    ///
+    /// let mut hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// assert_eq!(Ok("0".to_string()), hierarchy.try_spawn(""));
+    /// assert_eq!(Err(ParseError::InvalidFormat), hierarchy.try_spawn("."));
+    /// ```
+    pub fn try_spawn<S: AsRef<str>>(&mut self, path: S) -> Result<String, ParseError> {
+        Ok(self.try_scope(path)?.next())
+    }
+}
+
+/// The children of a node of a [`Hierarchy`], borrowing it so that the
+/// children handed out are recorded in it.
+/// ```
+/// use light_id::{Hierarchy, LightId};
+///
+/// let mut hierarchy = Hierarchy::new(LightId::new());
+/// let mut scope = hierarchy.scope("2");
+///
+/// assert_eq!("2.0", scope.next());
+/// assert_eq!("2.1", scope.current());
+/// assert_eq!(1, scope.count());
+/// ```
+#[derive(Debug)]
+pub struct ChildScope<'a> {
+    hierarchy: &'a mut Hierarchy,
+    node: Vec<usize>,
+}
+
+impl ChildScope<'_> {
+    /// Returns the path of the node.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let mut hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// assert_eq!("3.1a", hierarchy.scope("3.1a").path());
+    /// ```
+    pub fn path(&self) -> String {
+        self.hierarchy.path(&self.node)
+    }
+
+    /// Returns the number of children spawned under the node.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let mut hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// hierarchy.spawn("1");
+    /// hierarchy.spawn("1");
+    ///
+    /// assert_eq!(2, hierarchy.scope("1").count());
+    /// ```
+    pub fn count(&self) -> usize {
+        self.hierarchy.children.get(&self.node).copied().unwrap_or(0)
+    }
+
+    /// Returns the path of the next child, without spawning it.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let mut hierarchy = Hierarchy::new(LightId::new());
+    ///
+    /// assert_eq!("0", hierarchy.scope("").current());
+    /// assert_eq!("0", hierarchy.spawn(""));
+    /// ```
+    pub fn current(&self) -> String {
+        self.child(&self.generator().current())
+    }
+
+    /// Spawns the next child, returning its path.
+    /// ```
+    /// use light_id::{Hierarchy, LightId};
+    ///
+    /// let mut hierarchy = Hierarchy::new(LightId::new());
+    /// let mut scope = hierarchy.scope("7");
+    ///
+    /// assert_eq!("7.0", scope.next());
+    /// assert_eq!("7.1", scope.next());
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> String {
+        let mut generator = self.generator();
+        let child = self.child(&generator.next());
+
+        self.hierarchy
+            .children
+            .insert(self.node.clone(), generator.count());
+        child
+    }
+
+    /// Returns the generator of the children, positioned after the spawned ones.
+    fn generator(&self) -> LightId {
+        let mut generator = self.hierarchy.generator(self.node.len()).clone();
+
+        generator.skip(self.count());
+        generator
+    }
+
+    /// Returns the path of the child of the node whose segment is `segment`.
+    fn child(&self, segment: &str) -> String {
+        match self.node.is_empty() {
+            true => segment.to_string(),
+            false => format!("{}{}{}", self.path(), self.hierarchy.separator, segment),
+        }
+    }
+}
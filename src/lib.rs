@@ -7,6 +7,27 @@
 //! - Lightweight and customizable ID generation.
 //! - Switching IDs between different bases.
 //! - Skipping and iterating through IDs.
+//! - Predefined alphabets (hexadecimal, base32, base58, ...), Unicode and word-based alphabets.
+//! - Tolerant parsing of human-entered IDs (case folding, aliases, separators).
+//! - Human-readable formatting (prefix, suffix, grouping).
+//! - Routing prefixed, type-tagged IDs to their entity type.
+//! - Mixed-radix IDs, with an alphabet per position.
+//! - Template-driven IDs such as `INV-{year}-{seq:6:0-9}-{check}`.
+//! - Validated configuration through a builder, with max length, step and check symbols.
+//! - Interleaved sequences for multi-master setups (step and offset).
+//! - Descending, fixed-width IDs sorting newest first.
+//! - String-sortable IDs and value-based comparison for any alphabet.
+//! - Fractional indexing, generating keys between two others for user-ordered lists.
+//! - Hierarchical dotted path IDs such as `3.1a.7`, with a child scope per node.
+//! - Arithmetic directly on IDs of any length (add, subtract, distance).
+//! - Balanced partitioning of ID ranges and sharding for parallel workers.
+//! - Sequences shared across processes through pluggable counter stores (memory, file, SQLite).
+//! - A ticket server leasing blocks of IDs over TCP, with a caching client.
+//! - An async generator prefetching blocks on tokio (`async` feature).
+//! - An allocator reusing released IDs, keeping them short.
+//! - Blocklists skipping offensive or reserved IDs, matched in linear time.
+//! - Content-addressed IDs derived from a SHA-256 hash, extended on collision.
+//! - Alphabet migrations that never reissue an ID and keep old IDs decodable.
 //!
 //! ## Example
 //!
@@ -56,12 +77,43 @@
 //! println!("Switched ID: {}", switched_id);
 //! ```
 //!
+//! ### Preset
+//!
+//! The `Preset` enum lists common alphabets, such as hexadecimal, base32 or base58.
+//!
+//! ```rust
+//! use light_id::{IdSwitcher, LightId, Preset};
+//!
+//! let mut generator = LightId::with_preset(Preset::Base58);
+//! println!("Current ID: {}", generator.next());
+//!
+//! let switcher = IdSwitcher::new(Preset::Base10, Preset::Base16);
+//! println!("Switched ID: {}", switcher.switch("255"));
+//! ```
+//!
 //! ## API Documentation
 //!
 //! See the detailed documentation for each struct, including methods and usage examples.
 //!
 //! - [`LightId`](struct.LightId.html)
 //! - [`IdSwitcher`](struct.IdSwitcher.html)
+//! - [`IdAllocator`](struct.IdAllocator.html)
+//! - [`LightIdBuilder`](struct.LightIdBuilder.html)
+//! - [`Blocklist`](struct.Blocklist.html)
+//! - [`ContentId`](struct.ContentId.html)
+//! - [`Alphabet`](struct.Alphabet.html)
+//! - [`Format`](struct.Format.html)
+//! - [`FractionalIndex`](struct.FractionalIndex.html)
+//! - [`Hierarchy`](struct.Hierarchy.html)
+//! - [`MixedRadix`](struct.MixedRadix.html)
+//! - [`Partition`](struct.Partition.html)
+//! - [`Preset`](enum.Preset.html)
+//! - [`Registry`](struct.Registry.html)
+//! - [`StoredLightId`](struct.StoredLightId.html)
+//! - [`CounterStore`](trait.CounterStore.html)
+//! - [`TicketServer`](struct.TicketServer.html)
+//! - [`TicketClient`](struct.TicketClient.html)
+//! - [`Template`](struct.Template.html)
 //!
 //! ## License
 //!
@@ -71,29 +123,138 @@
 //!
 //! - **0.1.0** (2023-12-14): Initial release
 
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use epoch::Epoch;
+
+mod allocator;
+mod alphabet;
+mod arithmetic;
+#[cfg(feature = "async")]
+mod asynchronous;
+mod blocklist;
+mod builder;
+mod content;
+mod epoch;
+mod error;
+mod format;
+mod fractional;
+mod hierarchy;
+mod mixed;
+mod partition;
+mod preset;
+mod radix;
+mod registry;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+mod store;
+mod template;
+mod ticket;
 mod utils;
 
+pub use allocator::IdAllocator;
+pub use alphabet::Alphabet;
+#[cfg(feature = "async")]
+pub use asynchronous::{AsyncCounterStore, AsyncLightId, Blocking};
+pub use blocklist::Blocklist;
+pub use builder::{LightIdBuilder, Sorting};
+pub use content::ContentId;
+pub use error::{ConfigError, ParseError};
+pub use format::Format;
+pub use fractional::FractionalIndex;
+pub use hierarchy::{ChildScope, Hierarchy};
+pub use mixed::MixedRadix;
+pub use partition::Partition;
+pub use preset::Preset;
+pub use radix::Radix;
+pub use registry::Registry;
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteStore;
+pub use store::{CounterStore, FileStore, MemoryStore, StoredLightId};
+pub use template::{Template, TemplateMatch};
+pub use ticket::{TicketClient, TicketServer};
+
 pub const DEFAULT_CHARACTERS: &str =
     "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
 
+/// Generates and parses incremental ids.
+///
+/// Two generators are equal when both their configuration and their count are
+/// equal. They are ordered by count first, then by configuration.
+/// [`Ord`] is not implemented, as `Ord::min` would shadow [`LightId::min`].
+/// ```
+/// use light_id::LightId;
+///
+/// let mut a = LightId::new();
+/// let b = LightId::from("abc");
+///
+/// a.increment();
+///
+/// assert!(a > b);
+/// assert_ne!(a.clone().decrement(), &b);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd)]
 pub struct LightId {
-    pub characters: Vec<char>,
-    pub min_length: usize,
+    // Declared first, so that the derived ordering compares the counts first
     status: usize,
+    pub alphabet: Alphabet,
+    pub min_length: usize,
+    pub format: Format,
+    max_length: Option<usize>,
+    step: usize,
+    offset: usize,
+    // Positions skipped by migrations, so that new ids are longer than the old ones
+    shift: usize,
+    descending: bool,
+    checksum: bool,
+    blocklist: Blocklist,
+    epochs: Vec<Epoch>,
 }
 
-impl PartialEq for LightId {
-    fn eq(&self, other: &Self) -> bool {
-        self.count() == other.count() && self.characters == other.characters
+impl Default for LightId {
+    fn default() -> Self {
+        LightId::new()
     }
 }
 
-impl PartialOrd for LightId {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.count().cmp(&other.count()))
+impl fmt::Display for LightId {
+    /// Displays the current id.
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let mut generator = LightId::new();
+    ///
+    /// generator.skip(10);
+    ///
+    /// assert_eq!("a", generator.to_string());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.current())
     }
 }
 
+impl FromStr for LightId {
+    type Err = ParseError;
+
+    /// Creates a [`LightId`] with the default configuration, positioned at the provided id.
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let generator: LightId = "1a".parse().unwrap();
+    ///
+    /// assert_eq!(72, generator.count());
+    /// assert!("1-a".parse::<LightId>().is_err());
+    /// ```
+    fn from_str(id: &str) -> Result<Self, Self::Err> {
+        let mut generator = LightId::new();
+        generator.status = generator.try_index(id)?;
+        Ok(generator)
+    }
+}
+
+#[allow(clippy::len_without_is_empty)]
 impl LightId {
     /// Creates a new [`LightId`] with the default configuration.
     /// ```
@@ -102,11 +263,7 @@ impl LightId {
     /// let mut generator = LightId::new();
     /// ```
     pub fn new() -> Self {
-        LightId {
-            status: 0,
-            characters: DEFAULT_CHARACTERS.chars().collect(),
-            min_length: 0,
-        }
+        LightId::from(DEFAULT_CHARACTERS)
     }
 
     /// Creates a new [`LightId`] with a custom alphabet
@@ -115,20 +272,60 @@ impl LightId {
     ///
     /// let generator = LightId::from("abcdef");
     /// ```
-    /// If the provided `characters` is equal to [`DEFAULT_CHARACTERS`], the expression can be replaced with
+    /// The alphabet can also be an [`Alphabet`] carrying normalization rules, or a [`Preset`].
+    /// If the provided alphabet is equal to [`DEFAULT_CHARACTERS`], the expression can be replaced with
     /// ```
     /// use light_id::LightId;
     ///
     /// let generator = LightId::new();
     /// ```
-    pub fn from<S: AsRef<str>>(characters: S) -> Self {
+    pub fn from<A: Into<Alphabet>>(alphabet: A) -> Self {
         LightId {
             status: 0,
-            characters: characters.as_ref().chars().collect(),
+            alphabet: alphabet.into(),
             min_length: 0,
+            format: Format::new(),
+            max_length: None,
+            step: 1,
+            offset: 0,
+            shift: 0,
+            descending: false,
+            checksum: false,
+            blocklist: Blocklist::new(),
+            epochs: vec![],
         }
     }
 
+    /// Returns a [`LightIdBuilder`], validating the configuration before creating the generator.
+    /// ```
+    /// use light_id::{LightId, Preset};
+    ///
+    /// let generator = LightId::builder()
+    ///     .alphabet(Preset::Base58)
+    ///     .min(4)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!("1111", generator.current());
+    /// ```
+    pub fn builder() -> LightIdBuilder {
+        LightIdBuilder::new()
+    }
+
+    /// Creates a new [`LightId`] using one of the predefined alphabets.
+    /// ```
+    /// use light_id::{LightId, Preset};
+    ///
+    /// let mut generator = LightId::with_preset(Preset::Base2);
+    ///
+    /// generator.skip(5);
+    ///
+    /// assert_eq!("101", generator.current());
+    /// ```
+    pub fn with_preset(preset: Preset) -> Self {
+        LightId::from(preset)
+    }
+
     /// Skip the first `n` ids
     /// ```
     /// use light_id::LightId;
@@ -156,11 +353,12 @@ impl LightId {
     /// assert_eq!("c", generator.current());
     /// ```
     pub fn last<S: AsRef<str>>(&mut self, id: S) -> &mut Self {
-        self.status = utils::parse_id(id.as_ref(), &self.characters);
+        self.status = self.index(id);
         self
     }
 
-    /// Sets the min length of the ids
+    /// Sets the min length of the ids, counted like [`LightId::len`]: the check
+    /// symbol, the prefix, the suffix and the separators come on top of it.
     /// ```
     /// use light_id::LightId;
     ///
@@ -176,7 +374,174 @@ impl LightId {
         self
     }
 
+    /// Sets the max length of the ids, [`LightId::next`] panicking once the
+    /// capacity is exceeded. It is counted like [`LightId::len`], without the
+    /// check symbol, the prefix, the suffix and the separators.
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let mut generator = LightId::from("abc");
+    ///
+    /// generator.max(2);
+    ///
+    /// assert_eq!(Some(9), generator.capacity());
+    ///
+    /// generator.checksum(true).skip(8);
+    ///
+    /// assert_eq!("ccc", generator.current());
+    /// assert_eq!(2, generator.len());
+    /// ```
+    pub fn max(&mut self, n: usize) -> &mut Self {
+        self.max_length = Some(n);
+        self
+    }
+
+    /// Sets the stride between two consecutive ids. The count, [`LightId::nth`]
+    /// and [`LightId::index`] only take the ids of the sequence into account.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let mut generator = LightId::new();
+    ///
+    /// generator.step(10);
+    ///
+    /// assert_eq!("0", generator.next());
+    /// assert_eq!("a", generator.next());
+    /// assert_eq!("k", generator.nth(2));
+    /// assert_eq!(2, generator.index("k"));
+    /// ```
+    pub fn step(&mut self, n: usize) -> &mut Self {
+        assert!(n > 0, "The step must be positive");
+
+        self.step = n;
+        self
+    }
+
+    /// Sets the value of the first id, so that generators sharing a step emit
+    /// interleaved sequences (like `auto_increment_offset`).
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let mut even = LightId::from("0123456789");
+    /// let mut odd = LightId::from("0123456789");
+    ///
+    /// even.step(2);
+    /// odd.step(2).offset(1);
+    ///
+    /// assert_eq!("0", even.next());
+    /// assert_eq!("1", odd.next());
+    /// assert_eq!("2", even.next());
+    /// assert_eq!("3", odd.next());
+    /// ```
+    pub fn offset(&mut self, n: usize) -> &mut Self {
+        self.offset = n;
+        self
+    }
+
+    /// Emits the ids in reverse order, from the largest value fitting the max
+    /// length down to zero, so that later ids sort first. The ids are padded to
+    /// the max length, sorting lexicographically with an alphabet in byte order.
+    ///
+    /// # Panics
+    ///
+    /// Generating or parsing ids panics if no max length is set, or if the
+    /// capacity exceeds `usize`.
+    /// ```
+    /// use light_id::{LightId, Preset};
+    ///
+    /// let mut generator = LightId::with_preset(Preset::Base10);
+    ///
+    /// generator.max(3).descending(true);
+    ///
+    /// assert_eq!("999", generator.next());
+    /// assert_eq!("998", generator.next());
+    /// assert_eq!(1, generator.index("998"));
+    /// ```
+    pub fn descending(&mut self, enabled: bool) -> &mut Self {
+        self.descending = enabled;
+        self
+    }
+
+    /// Appends a check symbol to the ids, computed with the Luhn mod N algorithm
+    /// and verified when parsing them.
+    /// ```
+    /// use light_id::{LightId, ParseError};
+    ///
+    /// let mut generator = LightId::from("0123456789");
+    ///
+    /// generator.checksum(true).skip(42);
+    ///
+    /// assert_eq!("422", generator.current());
+    /// assert_eq!(Ok(42), generator.try_index("422"));
+    /// assert_eq!(Err(ParseError::InvalidChecksum), generator.try_index("423"));
+    /// ```
+    pub fn checksum(&mut self, enabled: bool) -> &mut Self {
+        self.checksum = enabled;
+        self
+    }
+
+    /// Skips the ids matching the [`Blocklist`] in [`LightId::next`] and [`LightId::current`].
+    /// The blocked ids keep their position, so [`LightId::nth`] and [`LightId::index`]
+    /// still map every position to its id, blocked or not.
+    ///
+    /// The blocklist is matched against the symbols of the ids only, without their
+    /// check symbol, prefix, suffix and separators, so that a separator cannot
+    /// split a blocked word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prefix or the suffix contains a blocked substring.
+    /// ```
+    /// use light_id::{Blocklist, LightId};
+    ///
+    /// let mut blocklist = Blocklist::new();
+    /// blocklist.substrings(["b"]);
+    ///
+    /// let mut generator = LightId::from("abc");
+    /// generator.blocklist(blocklist);
+    ///
+    /// assert_eq!("a", generator.next());
+    /// assert_eq!("c", generator.next());
+    /// assert_eq!("ca", generator.next());
+    /// assert_eq!("b", generator.nth(1));
+    /// assert_eq!(8, generator.index("cc"));
+    /// ```
+    pub fn blocklist(&mut self, blocklist: Blocklist) -> &mut Self {
+        self.blocklist = blocklist;
+        self.check_format();
+        self
+    }
+
+    /// Returns the number of distinct ids of the sequence when a max length is
+    /// set, or `None` if there is no limit (or if it exceeds `usize`).
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let mut generator = LightId::from("0123456789");
+    ///
+    /// assert_eq!(None, generator.capacity());
+    ///
+    /// generator.max(3);
+    ///
+    /// assert_eq!(Some(1000), generator.capacity());
+    /// ```
+    pub fn capacity(&self) -> Option<usize> {
+        self.values().map(|values| {
+            values
+                .saturating_sub(self.offset)
+                .div_ceil(self.step)
+                .saturating_sub(self.shift)
+        })
+    }
+
     /// Sets the possible characters, in their order of importance (custom base)
+    ///
+    /// The count is kept, so the new ids may collide with the ones already issued.
+    /// Use [`LightId::migrate`] to switch alphabets on a live generator.
     /// ```
     /// use light_id::LightId;
     ///
@@ -186,25 +551,125 @@ impl LightId {
     ///
     /// assert_eq!("a", generator.current());
     /// ```
-    pub fn chars<S: AsRef<str>>(&mut self, characters: S) -> &mut Self {
-        self.characters = characters.as_ref().chars().collect();
+    pub fn chars<A: Into<Alphabet>>(&mut self, alphabet: A) -> &mut Self {
+        self.alphabet = alphabet.into();
+        self
+    }
+
+    /// Switches to a new alphabet, keeping the count. The ids issued so far stay
+    /// decodable, and the new ids skip ahead so that they are longer than any of
+    /// them, which guarantees that no id is issued twice.
+    ///
+    /// The rest of the configuration should be set before migrating, as the
+    /// length of the new ids is computed from it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new ids cannot be longer than the old ones, because of
+    /// the max length or of a descending order.
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let mut generator = LightId::from("abcdef");
+    ///
+    /// generator.skip(10);
+    /// generator.migrate("012abc");
+    ///
+    /// // The ids issued so far have up to 2 symbols
+    /// assert_eq!("100", generator.next());
+    /// assert_eq!("101", generator.next());
+    ///
+    /// assert_eq!("ba", generator.nth(6));
+    /// assert_eq!(6, generator.index("ba"));
+    /// assert_eq!(10, generator.index("100"));
+    /// ```
+    pub fn migrate<A: Into<Alphabet>>(&mut self, alphabet: A) -> &mut Self {
+        let mut retired = self.clone();
+        retired.epochs.clear();
+
+        let start = self.epochs.last().map_or(0, |epoch| epoch.end());
+        let end = std::cmp::max(start, self.status);
+
+        self.epochs.push(Epoch::new(retired, start, end));
+        self.alphabet = alphabet.into();
+        self.separate(end);
         self
     }
 
-    /// Clone the current [`LighId`].
+    /// Returns the number of migrations the generator went through.
     /// ```
     /// use light_id::LightId;
     ///
     /// let mut generator = LightId::new();
     ///
-    /// let mut generator2 = generator.clone();
+    /// assert_eq!(0, generator.epoch());
+    ///
+    /// generator.migrate("abc");
+    ///
+    /// assert_eq!(1, generator.epoch());
     /// ```
-    pub fn clone(&self) -> Self {
-        LightId {
-            status: self.status.clone(),
-            characters: self.characters.clone(),
-            min_length: self.min_length.clone(),
-        }
+    pub fn epoch(&self) -> usize {
+        self.epochs.len()
+    }
+
+    /// Sets the prefix of the ids, removed again when parsing them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prefix contains a substring of the [`Blocklist`].
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let mut generator = LightId::new();
+    ///
+    /// generator.prefix("ord_");
+    ///
+    /// assert_eq!("ord_0", generator.current());
+    /// assert_eq!(0, generator.index("ord_0"));
+    /// ```
+    pub fn prefix<S: AsRef<str>>(&mut self, prefix: S) -> &mut Self {
+        self.format.prefix(prefix);
+        self.check_format();
+        self
+    }
+
+    /// Sets the suffix of the ids, removed again when parsing them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the suffix contains a substring of the [`Blocklist`].
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let mut generator = LightId::new();
+    ///
+    /// generator.suffix("_v1");
+    ///
+    /// assert_eq!("0_v1", generator.current());
+    /// ```
+    pub fn suffix<S: AsRef<str>>(&mut self, suffix: S) -> &mut Self {
+        self.format.suffix(suffix);
+        self.check_format();
+        self
+    }
+
+    /// Splits the ids in groups of `size` symbols, counted from the end of the id.
+    /// Parsing the ids requires the separators at the same positions, so that
+    /// each id has a single spelling.
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let mut generator = LightId::new();
+    ///
+    /// generator.min(12).group(4, "-");
+    ///
+    /// assert_eq!("0000-0000-0000", generator.current());
+    /// assert_eq!(0, generator.index("0000-0000-0000"));
+    /// assert!(generator.try_index("000000-000000").is_err());
+    /// ```
+    pub fn group<S: AsRef<str>>(&mut self, size: usize, separator: S) -> &mut Self {
+        self.format.group(size, separator);
+        self
     }
 
     /// Returns the current number of ids
@@ -218,7 +683,7 @@ impl LightId {
     /// assert_eq!(1, generator.count());
     /// ```
     pub fn count(&self) -> usize {
-        return self.status;
+        self.status
     }
 
     /// Decrements the current id.
@@ -309,9 +774,11 @@ impl LightId {
     ///
     /// assert_eq!("0", value);
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> String {
-        self.status += 1;
-        utils::format_id(&(&self.status - 1), &self.min_length, &self.characters)
+        let n = self.available(self.status);
+        self.status = n + 1;
+        self.nth(n)
     }
 
     /// Returns the current id.
@@ -323,10 +790,11 @@ impl LightId {
     /// assert_eq!("0", generator.current());
     /// ```
     pub fn current(&self) -> String {
-        utils::format_id(&self.status, &self.min_length, &self.characters)
+        self.nth(self.available(self.status))
     }
 
-    /// Returns the length of the current id.
+    /// Returns the number of symbols of the current id, without its check symbol,
+    /// prefix, suffix and separators, as counted by [`LightId::min`] and [`LightId::max`].
     /// ```
     /// use light_id::LightId;
     ///
@@ -337,18 +805,16 @@ impl LightId {
     /// generator.increment_by(100);
     ///
     /// assert_eq!(2, generator.len());
+    ///
+    /// generator.checksum(true);
+    ///
+    /// assert_eq!(2, generator.len());
     /// ```
     pub fn len(&self) -> usize {
-        if self.status == 0 {
-            return std::cmp::max(self.min_length, 1);
-        }
-        return std::cmp::max(
-            self.min_length,
-            self.status.ilog(self.characters.len()) as usize + 1,
-        );
+        self.symbols_at(self.available(self.status)) - self.checksum as usize
     }
 
-    /// Returns the nth id.
+    /// Returns the nth id, using the alphabet of the epoch it was issued in.
     /// ```
     /// use light_id::LightId;
     /// 
@@ -357,7 +823,10 @@ impl LightId {
     /// assert_eq!("2", generator.nth(2));
     /// ```
     pub fn nth(&self, n: usize) -> String {
-        utils::format_id(&n, &self.min_length, &self.characters)
+        match self.epochs.iter().find(|epoch| epoch.owns(n)) {
+            Some(epoch) => epoch.nth(n),
+            None => self.render(n),
+        }
     }
 
     /// Returns the index of the provided id
@@ -369,14 +838,322 @@ impl LightId {
     /// assert_eq!(2, generator.index("2"));
     /// ```
     pub fn index<S: AsRef<str>>(&self, id: S) -> usize {
-        utils::parse_id(id.as_ref(), &self.characters)
+        self.try_index(id).expect("Invalid id")
+    }
+
+    /// Returns the index of the provided id, or the reason it cannot be parsed.
+    /// ```
+    /// use light_id::{LightId, ParseError};
+    ///
+    /// let generator = LightId::from("abc");
+    ///
+    /// assert_eq!(Ok(2), generator.try_index("c"));
+    /// assert_eq!(Err(ParseError::InvalidCharacter('d')), generator.try_index("d"));
+    /// ```
+    pub fn try_index<S: AsRef<str>>(&self, id: S) -> Result<usize, ParseError> {
+        if let Some(n) = self.epochs.iter().find_map(|epoch| epoch.issued(id.as_ref())) {
+            return Ok(n);
+        }
+
+        let mut value = utils::from_digits(&self.parse_value(id.as_ref())?, self.alphabet.len())?;
+
+        if self.descending {
+            value = self.descending_values() - 1 - value;
+        }
+
+        match value.checked_sub(self.offset) {
+            Some(delta) if delta % self.step == 0 => self.unshift(delta / self.step),
+            _ => Err(ParseError::OutOfSequence),
+        }
+    }
+
+    /// Compares two ids by their position in the sequence, whatever the alphabet.
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use light_id::LightId;
+    ///
+    /// let generator = LightId::from("0123456789");
+    ///
+    /// assert_eq!(Ordering::Less, generator.compare_ids("9", "10"));
+    /// assert!("9" > "10");
+    /// ```
+    pub fn compare_ids<A: AsRef<str>, B: AsRef<str>>(&self, a: A, b: B) -> Ordering {
+        self.try_compare_ids(a, b).expect("Invalid id")
+    }
+
+    /// Compares two ids by their position in the sequence, or returns the reason
+    /// one of them cannot be parsed.
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use light_id::{LightId, ParseError};
+    ///
+    /// let generator = LightId::from("abcdef");
+    ///
+    /// assert_eq!(Ok(Ordering::Greater), generator.try_compare_ids("ba", "f"));
+    /// assert_eq!(Err(ParseError::InvalidCharacter('z')), generator.try_compare_ids("a", "z"));
+    /// ```
+    pub fn try_compare_ids<A: AsRef<str>, B: AsRef<str>>(
+        &self,
+        a: A,
+        b: B,
+    ) -> Result<Ordering, ParseError> {
+        Ok(utils::compare_digits(
+            &self.try_position(a.as_ref())?,
+            &self.try_position(b.as_ref())?,
+        ))
+    }
+
+    /// Returns the first index from `n` whose id is not blocked.
+    fn available(&self, mut n: usize) -> usize {
+        while !self.exceeds(n) && self.is_blocked_at(n) {
+            n += 1;
+        }
+        n
+    }
+
+    /// Returns whether the nth id is blocked, matching the blocklist against
+    /// its value symbols only.
+    pub(crate) fn is_blocked_at(&self, n: usize) -> bool {
+        if self.blocklist.is_empty() {
+            return false;
+        }
+
+        match self.epochs.iter().find(|epoch| epoch.owns(n)) {
+            Some(epoch) => epoch.is_blocked_at(n),
+            None => {
+                let value = utils::to_digits(self.value(n), self.alphabet.len());
+                let symbols: String = self
+                    .pad(&value)
+                    .iter()
+                    .map(|digit| self.alphabet.symbols()[*digit].as_str())
+                    .collect();
+
+                self.blocklist.is_blocked(symbols)
+            }
+        }
+    }
+
+    /// Panics if the prefix or the suffix contains a blocked substring, as
+    /// they would show a blocked word in every id.
+    fn check_format(&self) {
+        assert!(
+            !self.blocklist.blocks_format(&self.format),
+            "The prefix or suffix contains a blocked word"
+        );
+    }
+
+    /// Shifts the positions following `end`, so that their ids are longer than
+    /// the ones issued in the previous epochs and cannot be equal to them.
+    fn separate(&mut self, end: usize) {
+        let longest = self
+            .epochs
+            .iter()
+            .map(|epoch| epoch.longest())
+            .max()
+            .unwrap_or(0);
+
+        if longest == 0 {
+            return;
+        }
+
+        let shortest = self
+            .alphabet
+            .symbols()
+            .iter()
+            .min_by_key(|symbol| symbol.len())
+            .expect("The alphabet is empty");
+
+        let mut symbols = 1;
+
+        while self.rendered_len(symbols, shortest) <= longest {
+            symbols += 1;
+        }
+
+        let check = self.checksum as usize;
+
+        if self.width() + check >= symbols {
+            return;
+        }
+
+        assert!(
+            !self.descending && self.max_length.is_none_or(|max| max + check >= symbols),
+            "The new ids cannot be longer than the previous ones"
+        );
+
+        let first = u32::try_from(symbols - check - 1)
+            .ok()
+            .and_then(|digits| self.alphabet.len().checked_pow(digits))
+            .expect("Capacity exceeded");
+
+        let position = first.saturating_sub(self.offset).div_ceil(self.step);
+
+        self.shift = self.shift.max(position.saturating_sub(end));
+    }
+
+    /// Returns the length in bytes of an id made of `symbols` times `symbol`.
+    fn rendered_len(&self, symbols: usize, symbol: &str) -> usize {
+        self.format
+            .render(&vec![symbol; symbols], self.alphabet.separator())
+            .len()
+    }
+
+    /// Returns the length in bytes of the longest id up to the nth one.
+    pub(crate) fn longest(&self, n: usize) -> usize {
+        let longest = self
+            .alphabet
+            .symbols()
+            .iter()
+            .max_by_key(|symbol| symbol.len())
+            .expect("The alphabet is empty");
+
+        self.rendered_len(self.symbols_at(n), longest)
+    }
+
+    /// Returns the number of symbols of the nth id, including its check symbol.
+    fn symbols_at(&self, n: usize) -> usize {
+        let value = self.value(n);
+        let digits = match value {
+            0 => 1,
+            _ => value.ilog(self.alphabet.len()) as usize + 1,
+        };
+
+        std::cmp::max(self.width(), digits) + self.checksum as usize
+    }
+
+    /// Converts a position counted from the offset into a position of the
+    /// sequence, rejecting the ones skipped by migrations or owned by a previous epoch.
+    fn unshift(&self, n: usize) -> Result<usize, ParseError> {
+        let end = self.epochs.last().map_or(0, |epoch| epoch.end());
+
+        match n.checked_sub(self.shift) {
+            Some(n) if n >= end => Ok(n),
+            _ => Err(ParseError::OutOfSequence),
+        }
+    }
+
+    /// Returns whether the nth id is past the capacity, or its value exceeds `usize`.
+    pub(crate) fn exceeds(&self, n: usize) -> bool {
+        let overflows = n
+            .checked_add(self.shift)
+            .and_then(|n| n.checked_mul(self.step))
+            .and_then(|value| value.checked_add(self.offset))
+            .is_none();
+
+        overflows || self.capacity().is_some_and(|capacity| n >= capacity)
+    }
+
+    /// Returns the value encoded by the nth id of the sequence.
+    fn value(&self, n: usize) -> usize {
+        let value = n
+            .checked_add(self.shift)
+            .and_then(|n| n.checked_mul(self.step))
+            .and_then(|value| value.checked_add(self.offset))
+            .expect("Capacity exceeded");
+
+        if self.descending {
+            self.descending_values()
+                .checked_sub(value + 1)
+                .expect("Capacity exceeded")
+        } else {
+            value
+        }
+    }
+
+    /// Returns the number of values fitting the max length, if any.
+    fn values(&self) -> Option<usize> {
+        self.max_length
+            .and_then(|max| self.alphabet.len().checked_pow(max.try_into().ok()?))
+    }
+
+    fn descending_values(&self) -> usize {
+        self.values()
+            .expect("Descending ids need a max length fitting in usize")
+    }
+
+    /// Returns the number of symbols the ids are padded to.
+    fn width(&self) -> usize {
+        match self.max_length {
+            Some(max) if self.descending => max,
+            _ => self.min_length,
+        }
+    }
+
+    /// Returns the digits of the value encoded by an id, checking its format,
+    /// check symbol and length.
+    fn parse_value(&self, id: &str) -> Result<Vec<usize>, ParseError> {
+        let raw = self
+            .format
+            .strip_with(id, self.alphabet.separator())
+            .ok_or(ParseError::InvalidFormat)?;
+
+        let mut digits = utils::parse_digits(&raw, &self.alphabet)?;
+
+        if self.checksum {
+            let check = digits.pop().ok_or(ParseError::InvalidLength)?;
+
+            if check != utils::check_digit(&digits, self.alphabet.len()) {
+                return Err(ParseError::InvalidChecksum);
+            }
+        }
+
+        if self.max_length.is_some_and(|max| digits.len() > max) {
+            return Err(ParseError::InvalidLength);
+        }
+
+        Ok(digits)
+    }
+
+    fn render(&self, n: usize) -> String {
+        if let Some(capacity) = self.capacity() {
+            assert!(n < capacity, "Capacity exceeded");
+        }
+
+        self.render_value(&utils::to_digits(self.value(n), self.alphabet.len()))
+    }
+
+    /// Returns the digits of a value, padded to the width of the ids.
+    fn pad(&self, value: &[usize]) -> Vec<usize> {
+        let padding = self.width().max(1).saturating_sub(value.len());
+
+        std::iter::repeat_n(0, padding)
+            .chain(value.iter().copied())
+            .collect()
+    }
+
+    /// Returns the id encoding a value made of digits.
+    fn render_value(&self, value: &[usize]) -> String {
+        let mut digits = self.pad(value);
+
+        if self.checksum {
+            digits.push(utils::check_digit(&digits, self.alphabet.len()));
+        }
+
+        let symbols: Vec<&str> = digits
+            .iter()
+            .map(|digit| self.alphabet.symbols()[*digit].as_str())
+            .collect();
+
+        self.format.render(&symbols, self.alphabet.separator())
     }
 }
 
+/// Converts ids from a source [`Radix`] to a target one.
+///
+/// Switchers are ordered by source first, then by target, so that they can be
+/// used as keys of ordered collections.
+/// ```
+/// use light_id::IdSwitcher;
+///
+/// let a = IdSwitcher::new("01", "abc");
+/// let b = IdSwitcher::new("012", "abc");
+///
+/// assert!(a < b);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct IdSwitcher {
-    source: Vec<char>,
+    source: Radix,
     source_min: usize,
-    target: Vec<char>,
+    target: Radix,
     target_min: usize,
 }
 
@@ -389,31 +1166,15 @@ impl IdSwitcher {
     /// 
     /// let switcher = IdSwitcher::new("0123456789", "abcdefghij");
     /// ```
-    pub fn new<S: AsRef<str>>(source: S, target: S) -> Self {
+    pub fn new<S: Into<Radix>, T: Into<Radix>>(source: S, target: T) -> Self {
         IdSwitcher {
-            source: source.as_ref().chars().collect(),
+            source: source.into(),
             source_min: 0,
-            target: target.as_ref().chars().collect(),
+            target: target.into(),
             target_min: 0
         }
     }
 
-    /// Returns a copy of the [`IdSwitcher`].
-    /// ```
-    /// use light_id::IdSwitcher;
-    /// 
-    /// let switcher = IdSwitcher::new("0123456789", "abcdefghij");
-    /// let switcher_2 = switcher.clone();
-    /// ```
-    pub fn clone (&self) -> Self {
-        IdSwitcher {
-            source: self.source.clone(),
-            source_min: self.source_min.clone(),
-            target: self.target.clone(),
-            target_min: self.target_min.clone(),
-        }
-    }
-
     /// Sets the min length of the converted ids.
     /// ```
     /// use light_id::IdSwitcher;
@@ -455,7 +1216,7 @@ impl IdSwitcher {
     /// assert_eq!("a", switcher.switch_count(0));
     /// ```
     pub fn switch_count(&self, id: usize) -> String {
-        utils::format_id(&id, &self.target_min, &self.target)
+        self.target.format(id, self.target_min)
     }
 
     /// Switches an id from the source base to the target base.
@@ -467,7 +1228,21 @@ impl IdSwitcher {
     /// assert_eq!("a", switcher.switch("0"));
     /// ```
     pub fn switch<S: AsRef<str>>(&self, id: S) -> String {
-        self.switch_count(utils::parse_id(id.as_ref(), &self.source))
+        self.switch_count(self.source.parse(id.as_ref()).expect("Invalid id"))
+    }
+
+    /// Switches an id from the source base to the target base, or returns the
+    /// reason it cannot be parsed.
+    /// ```
+    /// use light_id::{IdSwitcher, ParseError};
+    ///
+    /// let switcher = IdSwitcher::new("0123456789", "abcdefghij");
+    ///
+    /// assert_eq!(Ok("b".to_string()), switcher.try_switch("1"));
+    /// assert_eq!(Err(ParseError::InvalidCharacter('x')), switcher.try_switch("x"));
+    /// ```
+    pub fn try_switch<S: AsRef<str>>(&self, id: S) -> Result<String, ParseError> {
+        Ok(self.switch_count(self.source.parse(id.as_ref())?))
     }
 
     /// Switches an id count from the target base to the source base.
@@ -479,7 +1254,7 @@ impl IdSwitcher {
     /// assert_eq!("0", switcher.switch_count_reverse(0));
     /// ```
     pub fn switch_count_reverse(&self, id: usize) -> String {
-        utils::format_id(&id, &self.source_min, &self.source)
+        self.source.format(id, self.source_min)
     }
 
     /// Switches an id from the target base to the source base.
@@ -491,6 +1266,19 @@ impl IdSwitcher {
     /// assert_eq!("0", switcher.switch_reverse("a"));
     /// ```
     pub fn switch_reverse<S: AsRef<str>>(&self, id: S) -> String {
-        self.switch_count_reverse(utils::parse_id(id.as_ref(), &self.target))
+        self.switch_count_reverse(self.target.parse(id.as_ref()).expect("Invalid id"))
+    }
+
+    /// Switches an id from the target base to the source base, or returns the
+    /// reason it cannot be parsed.
+    /// ```
+    /// use light_id::IdSwitcher;
+    ///
+    /// let switcher = IdSwitcher::new("0123456789", "abcdefghij");
+    ///
+    /// assert_eq!(Ok("1".to_string()), switcher.try_switch_reverse("b"));
+    /// ```
+    pub fn try_switch_reverse<S: AsRef<str>>(&self, id: S) -> Result<String, ParseError> {
+        Ok(self.switch_count_reverse(self.target.parse(id.as_ref())?))
     }
 }
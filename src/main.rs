@@ -0,0 +1,228 @@
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+use light_id::{Alphabet, IdSwitcher, LightId, Preset};
+
+const USAGE: &str = "\
+Usage: light-id <command> [options] [values...]
+
+Commands:
+  gen      Generate ids          [--alphabet A] [--min N] [--count N] [--start N]
+  convert  Convert ids           --from A --to B [--min N] [ids...]
+  index    Print indexes of ids  [--alphabet A] [ids...]
+  nth      Print ids at indexes  [--alphabet A] [--min N] [indexes...]
+  inspect  Describe ids          [--alphabet A] [--min N] [ids...]
+
+Alphabets are either a preset name (base16, base58, crockford, ...) or the
+characters themselves. When no value is given, values are read from stdin,
+one per line.";
+
+struct Args {
+    command: String,
+    options: Vec<(String, String)>,
+    values: Vec<String>,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+        let command = args.next().ok_or(format!("missing command\n\n{}", USAGE))?;
+        let mut options = vec![];
+        let mut values = vec![];
+
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(option) => {
+                    let (name, value) = match option.split_once('=') {
+                        Some((name, value)) => (name.to_string(), value.to_string()),
+                        None => {
+                            let value = args
+                                .next()
+                                .ok_or(format!("missing value for --{}", option))?;
+                            (option.to_string(), value)
+                        }
+                    };
+                    options.push((name, value));
+                }
+                None => values.push(arg),
+            }
+        }
+
+        Ok(Args {
+            command,
+            options,
+            values,
+        })
+    }
+
+    fn check(&self, allowed: &[&str]) -> Result<(), String> {
+        match self.options.iter().find(|(name, _)| !allowed.contains(&name.as_str())) {
+            Some((name, _)) => Err(format!("unknown option --{} for {}", name, self.command)),
+            None => Ok(()),
+        }
+    }
+
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn number(&self, name: &str, default: usize) -> Result<usize, String> {
+        match self.option(name) {
+            Some(value) => number(value).map_err(|e| format!("--{}: {}", name, e)),
+            None => Ok(default),
+        }
+    }
+
+    fn alphabet(&self, name: &str) -> Result<Alphabet, String> {
+        let alphabet = match self.option(name) {
+            Some(value) => match Preset::from_name(value) {
+                Some(preset) => Alphabet::from(preset),
+                None => Alphabet::new(value),
+            },
+            None => Alphabet::from(Preset::Base62),
+        };
+
+        // Surfaces the checks of the builder, such as duplicate symbols
+        LightId::builder()
+            .alphabet(alphabet.clone())
+            .build()
+            .map_err(|e| format!("--{}: {}", name, e))?;
+
+        Ok(alphabet)
+    }
+
+    /// Calls `f` on each value, read from stdin when none is given.
+    fn each(&self, mut f: impl FnMut(&str) -> Result<String, String>) -> Result<(), String> {
+        let mut stdout = io::stdout().lock();
+        let mut emit = |value: &str| -> Result<(), String> {
+            let output = f(value).map_err(|e| format!("{:?}: {}", value, e))?;
+            writeln!(stdout, "{}", output).map_err(|e| e.to_string())
+        };
+
+        if !self.values.is_empty() {
+            return self.values.iter().try_for_each(|value| emit(value));
+        }
+
+        for line in io::stdin().lock().lines() {
+            let line = line.map_err(|e| e.to_string())?;
+            let value = line.trim();
+
+            if !value.is_empty() {
+                emit(value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn number(value: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid number {:?}", value))
+}
+
+fn generator(args: &Args) -> Result<LightId, String> {
+    LightId::builder()
+        .alphabet(args.alphabet("alphabet")?)
+        .min(args.number("min", 0)?)
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+fn run(args: Args) -> Result<(), String> {
+    match args.command.as_str() {
+        "gen" => {
+            args.check(&["alphabet", "min", "count", "start"])?;
+
+            let mut generator = generator(&args)?;
+            generator.skip(args.number("start", 0)?);
+
+            let mut stdout = io::stdout().lock();
+
+            for _ in 0..args.number("count", 1)? {
+                writeln!(stdout, "{}", generator.next()).map_err(|e| e.to_string())?;
+            }
+
+            Ok(())
+        }
+        "convert" => {
+            args.check(&["from", "to", "min"])?;
+
+            if args.option("from").is_none() || args.option("to").is_none() {
+                return Err("convert requires --from and --to".to_string());
+            }
+
+            let mut switcher = IdSwitcher::new(args.alphabet("from")?, args.alphabet("to")?);
+            switcher.min_target(args.number("min", 0)?);
+
+            args.each(|id| switcher.try_switch(id).map_err(|e| e.to_string()))
+        }
+        "index" => {
+            args.check(&["alphabet"])?;
+
+            let generator = generator(&args)?;
+
+            args.each(|id| {
+                generator
+                    .try_index(id)
+                    .map(|index| index.to_string())
+                    .map_err(|e| e.to_string())
+            })
+        }
+        "nth" => {
+            args.check(&["alphabet", "min"])?;
+
+            let generator = generator(&args)?;
+
+            args.each(|n| Ok(generator.nth(number(n)?)))
+        }
+        "inspect" => {
+            args.check(&["alphabet", "min"])?;
+
+            let mut generator = generator(&args)?;
+            let base = generator.alphabet.len();
+
+            args.each(|id| {
+                let index = generator.try_index(id).map_err(|e| e.to_string())?;
+                generator.skip(index);
+
+                let previous = match index {
+                    0 => "-".to_string(),
+                    _ => generator.nth(index - 1),
+                };
+
+                Ok(format!(
+                    "id: {}\ncanonical: {}\nindex: {}\nlength: {}\nbase: {}\nprevious: {}\nnext: {}",
+                    id,
+                    generator.current(),
+                    index,
+                    generator.len(),
+                    base,
+                    previous,
+                    generator.nth(index.saturating_add(1)),
+                ))
+            })
+        }
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        command => Err(format!("unknown command {:?}\n\n{}", command, USAGE)),
+    }
+}
+
+fn main() -> ExitCode {
+    let result = Args::parse(std::env::args().skip(1)).and_then(run);
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("light-id: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{utils, Alphabet, ParseError};
+
+/// A generator of fixed-width ids whose positions each have their own
+/// alphabet, such as license plates (letter, letter, digit, digit, digit).
+/// ```
+/// use light_id::MixedRadix;
+///
+/// let mut generator = MixedRadix::new(["ABC", "ABC", "0123456789"]);
+///
+/// assert_eq!("AA0", generator.next());
+/// assert_eq!("AA1", generator.next());
+/// assert_eq!("AB0", generator.nth(10));
+/// assert_eq!(90, generator.capacity().unwrap());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MixedRadix {
+    positions: Vec<Alphabet>,
+    separator: String,
+    status: usize,
+}
+
+impl MixedRadix {
+    /// Creates a new [`MixedRadix`], from the alphabets of each position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no position, or if an alphabet is empty.
+    /// ```
+    /// use light_id::{MixedRadix, Preset};
+    ///
+    /// let generator = MixedRadix::new([Preset::Base16, Preset::Base16, Preset::Base2]);
+    /// ```
+    pub fn new<I, A>(positions: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+        A: Into<Alphabet>,
+    {
+        let positions: Vec<Alphabet> = positions.into_iter().map(Into::into).collect();
+
+        assert!(!positions.is_empty(), "At least one position is required");
+        assert!(
+            positions.iter().all(|alphabet| alphabet.len() > 0),
+            "Empty alphabet"
+        );
+
+        MixedRadix {
+            positions,
+            separator: String::new(),
+            status: 0,
+        }
+    }
+
+    /// Sets the string joining the positions of the ids, needed when a position
+    /// uses a word alphabet.
+    /// ```
+    /// use light_id::MixedRadix;
+    ///
+    /// let mut generator = MixedRadix::new(["ABC", "0123456789"]);
+    ///
+    /// generator.separator("-");
+    ///
+    /// assert_eq!("A-0", generator.current());
+    /// assert_eq!(1, generator.index("A-1"));
+    /// ```
+    pub fn separator<S: AsRef<str>>(&mut self, separator: S) -> &mut Self {
+        self.separator = separator.as_ref().to_string();
+        self
+    }
+
+    /// Returns the alphabets of each position.
+    /// ```
+    /// use light_id::MixedRadix;
+    ///
+    /// assert_eq!(2, MixedRadix::new(["AB", "01"]).positions().len());
+    /// ```
+    pub fn positions(&self) -> &[Alphabet] {
+        &self.positions
+    }
+
+    /// Returns the number of distinct ids, or `None` if it exceeds `usize`.
+    /// ```
+    /// use light_id::MixedRadix;
+    ///
+    /// let generator = MixedRadix::new(["ABCDEFGHIJKLMNOPQRSTUVWXYZ", "0123456789"]);
+    ///
+    /// assert_eq!(Some(260), generator.capacity());
+    /// ```
+    pub fn capacity(&self) -> Option<usize> {
+        self.positions
+            .iter()
+            .try_fold(1usize, |capacity, alphabet| capacity.checked_mul(alphabet.len()))
+    }
+
+    /// Skip the first `n` ids
+    /// ```
+    /// use light_id::MixedRadix;
+    ///
+    /// let mut generator = MixedRadix::new(["AB", "01"]);
+    ///
+    /// generator.skip(3);
+    ///
+    /// assert_eq!("B1", generator.current());
+    /// ```
+    pub fn skip(&mut self, n: usize) -> &mut Self {
+        self.status = n;
+        self
+    }
+
+    /// Returns the current number of ids
+    /// ```
+    /// use light_id::MixedRadix;
+    ///
+    /// let mut generator = MixedRadix::new(["AB", "01"]);
+    ///
+    /// generator.next();
+    ///
+    /// assert_eq!(1, generator.count());
+    /// ```
+    pub fn count(&self) -> usize {
+        self.status
+    }
+
+    /// Increments the id by one and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is exceeded.
+    /// ```
+    /// use light_id::MixedRadix;
+    ///
+    /// let mut generator = MixedRadix::new(["AB", "01"]);
+    ///
+    /// assert_eq!("A0", generator.next());
+    /// assert_eq!("A1", generator.next());
+    /// assert_eq!("B0", generator.next());
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> String {
+        let id = self.current();
+        self.status += 1;
+        id
+    }
+
+    /// Returns the current id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is exceeded.
+    /// ```
+    /// use light_id::MixedRadix;
+    ///
+    /// let generator = MixedRadix::new(["AB", "01"]);
+    ///
+    /// assert_eq!("A0", generator.current());
+    /// ```
+    pub fn current(&self) -> String {
+        self.nth(self.status)
+    }
+
+    /// Returns the nth id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not lower than the capacity.
+    /// ```
+    /// use light_id::MixedRadix;
+    ///
+    /// let generator = MixedRadix::new(["AB", "01"]);
+    ///
+    /// assert_eq!("B1", generator.nth(3));
+    /// ```
+    pub fn nth(&self, n: usize) -> String {
+        assert!(
+            self.capacity().is_none_or(|capacity| n < capacity),
+            "Capacity exceeded"
+        );
+
+        let width = self.positions.len();
+
+        utils::digits_at(&n, &width, |i| &self.positions[width - 1 - i]).join(&self.separator)
+    }
+
+    /// Returns the index of the provided id
+    /// ```
+    /// use light_id::MixedRadix;
+    ///
+    /// let generator = MixedRadix::new(["AB", "01"]);
+    ///
+    /// assert_eq!(3, generator.index("B1"));
+    /// ```
+    pub fn index<S: AsRef<str>>(&self, id: S) -> usize {
+        self.try_index(id).expect("Invalid id")
+    }
+
+    /// Returns the index of the provided id, or the reason it cannot be parsed.
+    /// ```
+    /// use light_id::{MixedRadix, ParseError};
+    ///
+    /// let generator = MixedRadix::new(["AB", "01"]);
+    ///
+    /// assert_eq!(Ok(3), generator.try_index("B1"));
+    /// assert_eq!(Err(ParseError::InvalidCharacter('2')), generator.try_index("B2"));
+    /// assert_eq!(Err(ParseError::InvalidLength), generator.try_index("B"));
+    /// ```
+    pub fn try_index<S: AsRef<str>>(&self, id: S) -> Result<usize, ParseError> {
+        let id = id.as_ref();
+        let symbols: Vec<&str> = match self.separator.as_str() {
+            "" => id.graphemes(true).collect(),
+            separator => id.split(separator).collect(),
+        };
+
+        if symbols.len() != self.positions.len() {
+            return Err(ParseError::InvalidLength);
+        }
+
+        symbols
+            .iter()
+            .zip(&self.positions)
+            .try_fold(0, |status, (symbol, alphabet)| {
+                match alphabet.position(symbol) {
+                    Some(Some(position)) => utils::push_digit(status, position, alphabet.len()),
+                    _ => Err(ParseError::invalid_symbol(symbol)),
+                }
+            })
+    }
+}
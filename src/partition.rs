@@ -0,0 +1,128 @@
+use crate::{utils, LightId, ParseError};
+
+/// A contiguous range of ids, from `start` included to `end` excluded,
+/// returned by [`LightId::partition`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Partition {
+    /// The first id of the range.
+    pub start: String,
+    /// The id following the last one of the range.
+    pub end: String,
+    /// The number of ids in the range.
+    pub count: usize,
+}
+
+impl LightId {
+    /// Splits the ids from `start` included to `end` excluded into `n` contiguous
+    /// partitions, whose counts differ by one at most.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either id is invalid, if `end` comes before `start` or if `n` is zero.
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let generator = LightId::from("0123456789");
+    ///
+    /// let partitions = generator.partition("100", "200", 3);
+    ///
+    /// assert_eq!(("100", "134"), (partitions[0].start.as_str(), partitions[0].end.as_str()));
+    /// assert_eq!(("134", "167"), (partitions[1].start.as_str(), partitions[1].end.as_str()));
+    /// assert_eq!(("167", "200"), (partitions[2].start.as_str(), partitions[2].end.as_str()));
+    /// assert_eq!(34, partitions[0].count);
+    /// ```
+    pub fn partition<A: AsRef<str>, B: AsRef<str>>(&self, start: A, end: B, n: usize) -> Vec<Partition> {
+        self.try_partition(start, end, n).expect("Invalid id")
+    }
+
+    /// Splits the ids from `start` included to `end` excluded into `n` contiguous
+    /// partitions, or returns the reason one of the ids cannot be parsed. Fails
+    /// with [`ParseError::Unordered`] if `end` comes before `start`, and with
+    /// [`ParseError::ZeroPartitions`] if `n` is zero.
+    /// ```
+    /// use light_id::{LightId, ParseError};
+    ///
+    /// let generator = LightId::from("abc");
+    ///
+    /// assert_eq!(Err(ParseError::InvalidCharacter('d')), generator.try_partition("a", "d", 2));
+    /// assert_eq!(Err(ParseError::Unordered), generator.try_partition("c", "a", 2));
+    /// assert_eq!(Err(ParseError::ZeroPartitions), generator.try_partition("a", "c", 0));
+    /// ```
+    pub fn try_partition<A: AsRef<str>, B: AsRef<str>>(
+        &self,
+        start: A,
+        end: B,
+        n: usize,
+    ) -> Result<Vec<Partition>, ParseError> {
+        let (start, end) = (start.as_ref(), end.as_ref());
+
+        if n == 0 {
+            return Err(ParseError::ZeroPartitions);
+        }
+
+        if self.try_compare_ids(start, end)?.is_gt() {
+            return Err(ParseError::Unordered);
+        }
+
+        let total = self.try_distance(start, end)?;
+        let mut partitions = Vec::with_capacity(n);
+        let mut boundary = start.to_string();
+
+        for i in 0..n {
+            let count = total / n + usize::from(i < total % n);
+            let next = if i == n - 1 {
+                end.to_string()
+            } else {
+                self.try_add(&boundary, count)?
+            };
+
+            partitions.push(Partition {
+                start: std::mem::replace(&mut boundary, next.clone()),
+                end: next,
+                count,
+            });
+        }
+
+        Ok(partitions)
+    }
+
+    /// Returns the shard of an id among `shards`, spreading consecutive ids
+    /// evenly. The shard only depends on the position of the id in the sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the id is invalid or if `shards` is zero.
+    /// ```
+    /// use light_id::LightId;
+    ///
+    /// let generator = LightId::new();
+    ///
+    /// assert_eq!(0, generator.shard("0", 4));
+    /// assert_eq!(3, generator.shard("7", 4));
+    /// assert_eq!(0, generator.shard("8", 4));
+    /// ```
+    pub fn shard<S: AsRef<str>>(&self, id: S, shards: usize) -> usize {
+        self.try_shard(id, shards).expect("Invalid id")
+    }
+
+    /// Returns the shard of an id among `shards`, or the reason it cannot be parsed.
+    /// Fails with [`ParseError::ZeroPartitions`] if `shards` is zero.
+    /// ```
+    /// use light_id::{LightId, ParseError};
+    ///
+    /// let generator = LightId::new();
+    ///
+    /// assert_eq!(Ok(2), generator.try_shard("a", 4));
+    /// assert_eq!(Err(ParseError::InvalidCharacter('!')), generator.try_shard("!", 4));
+    /// assert_eq!(Err(ParseError::ZeroPartitions), generator.try_shard("a", 0));
+    /// ```
+    pub fn try_shard<S: AsRef<str>>(&self, id: S, shards: usize) -> Result<usize, ParseError> {
+        if shards == 0 {
+            return Err(ParseError::ZeroPartitions);
+        }
+
+        let position = self.try_position(id.as_ref())?;
+
+        Ok(utils::div_digits(&position, shards, self.alphabet.len()).1)
+    }
+}
@@ -0,0 +1,140 @@
+/// Predefined alphabets, usable anywhere an alphabet is expected.
+/// ```
+/// use light_id::{IdSwitcher, LightId, Preset};
+///
+/// let mut generator = LightId::with_preset(Preset::Base16);
+/// generator.skip(255);
+///
+/// assert_eq!("ff", generator.current());
+///
+/// let switcher = IdSwitcher::new(Preset::Base10, Preset::Base2);
+///
+/// assert_eq!("101", switcher.switch("5"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Preset {
+    /// `01`
+    Base2,
+    /// `01234567`
+    Base8,
+    /// `0123456789`
+    Base10,
+    /// `0123456789abcdef`
+    Base16,
+    /// `0123456789ABCDEF`
+    Base16Upper,
+    /// RFC 4648 base32: `A-Z2-7`
+    Base32,
+    /// RFC 4648 base32 with extended hex alphabet: `0-9A-V`
+    Base32Hex,
+    /// Crockford's base32, without `I`, `L`, `O` and `U`
+    Crockford,
+    /// z-base-32, ordered to favour easy-to-read characters
+    ZBase32,
+    /// `0-9a-z`
+    Base36,
+    /// Bitcoin's base58, without `0`, `O`, `I` and `l`
+    Base58,
+    /// Flickr's base58, lowercase letters before uppercase ones
+    Base58Flickr,
+    /// `0-9a-zA-Z`, same as [`DEFAULT_CHARACTERS`](crate::DEFAULT_CHARACTERS)
+    Base62,
+    /// RFC 4648 URL and filename safe base64: `A-Za-z0-9-_`
+    Base64Url,
+    /// `0-9a-zA-Z` without the look-alikes `0`, `O`, `1`, `l` and `I`
+    NoLookAlikes,
+}
+
+impl Preset {
+    /// Every available preset.
+    pub const ALL: [Preset; 15] = [
+        Preset::Base2,
+        Preset::Base8,
+        Preset::Base10,
+        Preset::Base16,
+        Preset::Base16Upper,
+        Preset::Base32,
+        Preset::Base32Hex,
+        Preset::Crockford,
+        Preset::ZBase32,
+        Preset::Base36,
+        Preset::Base58,
+        Preset::Base58Flickr,
+        Preset::Base62,
+        Preset::Base64Url,
+        Preset::NoLookAlikes,
+    ];
+
+    /// Returns the name of the preset, as accepted by the command-line tool.
+    /// ```
+    /// use light_id::Preset;
+    ///
+    /// assert_eq!("base58flickr", Preset::Base58Flickr.name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Preset::Base2 => "base2",
+            Preset::Base8 => "base8",
+            Preset::Base10 => "base10",
+            Preset::Base16 => "base16",
+            Preset::Base16Upper => "base16upper",
+            Preset::Base32 => "base32",
+            Preset::Base32Hex => "base32hex",
+            Preset::Crockford => "crockford",
+            Preset::ZBase32 => "zbase32",
+            Preset::Base36 => "base36",
+            Preset::Base58 => "base58",
+            Preset::Base58Flickr => "base58flickr",
+            Preset::Base62 => "base62",
+            Preset::Base64Url => "base64url",
+            Preset::NoLookAlikes => "nolookalikes",
+        }
+    }
+
+    /// Returns the preset with the given name, ignoring case.
+    /// ```
+    /// use light_id::Preset;
+    ///
+    /// assert_eq!(Some(Preset::Crockford), Preset::from_name("Crockford"));
+    /// assert_eq!(None, Preset::from_name("base3"));
+    /// ```
+    pub fn from_name<S: AsRef<str>>(name: S) -> Option<Preset> {
+        Preset::ALL
+            .into_iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(name.as_ref()))
+    }
+
+    /// Returns the characters of the preset, in their order of importance.
+    /// ```
+    /// use light_id::Preset;
+    ///
+    /// assert_eq!("01234567", Preset::Base8.characters());
+    /// ```
+    pub const fn characters(&self) -> &'static str {
+        match self {
+            Preset::Base2 => "01",
+            Preset::Base8 => "01234567",
+            Preset::Base10 => "0123456789",
+            Preset::Base16 => "0123456789abcdef",
+            Preset::Base16Upper => "0123456789ABCDEF",
+            Preset::Base32 => "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567",
+            Preset::Base32Hex => "0123456789ABCDEFGHIJKLMNOPQRSTUV",
+            Preset::Crockford => "0123456789ABCDEFGHJKMNPQRSTVWXYZ",
+            Preset::ZBase32 => "ybndrfg8ejkmcpqxot1uwisza345h769",
+            Preset::Base36 => "0123456789abcdefghijklmnopqrstuvwxyz",
+            Preset::Base58 => "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+            Preset::Base58Flickr => "123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ",
+            Preset::Base62 => crate::DEFAULT_CHARACTERS,
+            Preset::Base64Url => {
+                "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"
+            }
+            Preset::NoLookAlikes => "23456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ",
+        }
+    }
+}
+
+impl AsRef<str> for Preset {
+    fn as_ref(&self) -> &str {
+        self.characters()
+    }
+}
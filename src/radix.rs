@@ -0,0 +1,53 @@
+use crate::{utils, Alphabet, MixedRadix, ParseError};
+
+/// One end of an [`IdSwitcher`](crate::IdSwitcher): either a single alphabet
+/// used by every position, or a [`MixedRadix`] layout.
+/// ```
+/// use light_id::{IdSwitcher, MixedRadix, Preset};
+///
+/// let plates = MixedRadix::new(["ABC", "ABC", "0123456789"]);
+/// let switcher = IdSwitcher::new(plates, Preset::Base10);
+///
+/// assert_eq!("10", switcher.switch("AB0"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Radix {
+    /// The same alphabet for every position, with ids growing as needed.
+    Uniform(Alphabet),
+    /// A fixed-width layout, with an alphabet per position.
+    Mixed(MixedRadix),
+}
+
+impl Radix {
+    pub(crate) fn format(&self, id: usize, min: usize) -> String {
+        match self {
+            Radix::Uniform(alphabet) => utils::format_id(&id, &min, alphabet),
+            Radix::Mixed(mixed) => mixed.nth(id),
+        }
+    }
+
+    pub(crate) fn parse(&self, id: &str) -> Result<usize, ParseError> {
+        match self {
+            Radix::Uniform(alphabet) => utils::parse_id(id, alphabet),
+            Radix::Mixed(mixed) => mixed.try_index(id),
+        }
+    }
+}
+
+impl<A: Into<Alphabet>> From<A> for Radix {
+    fn from(alphabet: A) -> Self {
+        Radix::Uniform(alphabet.into())
+    }
+}
+
+impl From<MixedRadix> for Radix {
+    fn from(mixed: MixedRadix) -> Self {
+        Radix::Mixed(mixed)
+    }
+}
+
+impl From<&MixedRadix> for Radix {
+    fn from(mixed: &MixedRadix) -> Self {
+        Radix::Mixed(mixed.clone())
+    }
+}
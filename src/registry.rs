@@ -0,0 +1,124 @@
+use crate::{LightId, ParseError};
+
+/// Maps prefixes to generators, so that any id of a system can be routed to
+/// the entity type it belongs to (Stripe-style `usr_3fA9`, `inv_00Zq`).
+/// ```
+/// use light_id::{LightId, Registry};
+///
+/// let mut registry = Registry::new();
+///
+/// registry.register("user", "usr_", LightId::new());
+/// registry.register("invoice", "inv_", LightId::new());
+///
+/// let id = registry.get_mut(&"invoice").unwrap().next();
+///
+/// assert_eq!("inv_0", id);
+/// assert_eq!(Ok((&"invoice", 0)), registry.parse(&id));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Registry<K> {
+    entries: Vec<(K, LightId)>,
+}
+
+impl<K> Default for Registry<K> {
+    fn default() -> Self {
+        Registry { entries: vec![] }
+    }
+}
+
+impl<K: PartialEq> Registry<K> {
+    /// Creates an empty [`Registry`].
+    /// ```
+    /// use light_id::Registry;
+    ///
+    /// let registry: Registry<&str> = Registry::new();
+    /// ```
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    /// Registers the generator of an entity type, replacing its prefix with `prefix`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entity type is already registered, or if `prefix` starts
+    /// with another registered prefix or is the start of one, as their ids could
+    /// not be told apart (`us5` could be the id `s5` of the `u` prefix).
+    /// ```
+    /// use light_id::{LightId, Preset, Registry};
+    ///
+    /// let mut registry = Registry::new();
+    ///
+    /// registry.register("user", "usr_", LightId::with_preset(Preset::Base58));
+    ///
+    /// assert_eq!("usr_1", registry.get_mut(&"user").unwrap().next());
+    /// ```
+    pub fn register<S: AsRef<str>>(&mut self, kind: K, prefix: S, mut generator: LightId) -> &mut Self {
+        let prefix = prefix.as_ref();
+
+        assert!(self.get(&kind).is_none(), "Entity type already registered");
+        assert!(
+            self.entries.iter().all(|(_, g)| {
+                let other = g.format.prefix_str();
+                !other.starts_with(prefix) && !prefix.starts_with(other)
+            }),
+            "Prefix overlapping a registered one"
+        );
+
+        generator.prefix(prefix);
+        self.entries.push((kind, generator));
+        self
+    }
+
+    /// Returns the generator of an entity type.
+    /// ```
+    /// use light_id::{LightId, Registry};
+    ///
+    /// let mut registry = Registry::new();
+    ///
+    /// registry.register("user", "usr_", LightId::new());
+    ///
+    /// assert_eq!("usr_0", registry.get(&"user").unwrap().current());
+    /// ```
+    pub fn get(&self, kind: &K) -> Option<&LightId> {
+        self.entries.iter().find(|(k, _)| k == kind).map(|(_, g)| g)
+    }
+
+    /// Returns the generator of an entity type, to issue new ids.
+    /// ```
+    /// use light_id::{LightId, Registry};
+    ///
+    /// let mut registry = Registry::new();
+    ///
+    /// registry.register("user", "usr_", LightId::new());
+    /// registry.get_mut(&"user").unwrap().skip(10);
+    ///
+    /// assert_eq!("usr_a", registry.get(&"user").unwrap().current());
+    /// ```
+    pub fn get_mut(&mut self, kind: &K) -> Option<&mut LightId> {
+        self.entries.iter_mut().find(|(k, _)| k == kind).map(|(_, g)| g)
+    }
+
+    /// Returns the entity type of an id along with its index.
+    /// ```
+    /// use light_id::{LightId, ParseError, Registry};
+    ///
+    /// let mut registry = Registry::new();
+    ///
+    /// registry.register("user", "usr_", LightId::new());
+    ///
+    /// assert_eq!(Ok((&"user", 10)), registry.parse("usr_a"));
+    /// assert_eq!(Err(ParseError::UnknownPrefix), registry.parse("org_a"));
+    /// ```
+    pub fn parse<S: AsRef<str>>(&self, id: S) -> Result<(&K, usize), ParseError> {
+        let id = id.as_ref();
+
+        let (kind, generator) = self
+            .entries
+            .iter()
+            .find(|(_, g)| id.starts_with(g.format.prefix_str()))
+            .ok_or(ParseError::UnknownPrefix)?;
+
+        Ok((kind, generator.try_index(id)?))
+    }
+}
@@ -0,0 +1,121 @@
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::CounterStore;
+
+/// A [`CounterStore`] kept in a row of the `light_id_counters` table of a
+/// SQLite database, requiring the `sqlite` feature.
+/// ```
+/// use light_id::{CounterStore, SqliteStore};
+///
+/// let store = SqliteStore::open(":memory:", "orders").unwrap();
+///
+/// assert_eq!(0, store.reserve(10).unwrap());
+/// assert_eq!(10, store.load().unwrap());
+/// ```
+#[derive(Debug)]
+pub struct SqliteStore {
+    connection: Connection,
+    name: String,
+}
+
+impl SqliteStore {
+    /// Opens the database at `path` and uses the counter called `name`,
+    /// creating the table and the counter if needed.
+    /// ```
+    /// use light_id::SqliteStore;
+    ///
+    /// let store = SqliteStore::open(":memory:", "orders").unwrap();
+    /// ```
+    pub fn open<P: AsRef<Path>, S: Into<String>>(path: P, name: S) -> rusqlite::Result<Self> {
+        let connection = Connection::open(path)?;
+
+        connection.busy_timeout(Duration::from_secs(5))?;
+
+        SqliteStore::new(connection, name)
+    }
+
+    /// Uses the counter called `name` of an open database, creating the table
+    /// and the counter if needed.
+    /// ```
+    /// use light_id::SqliteStore;
+    /// use rusqlite::Connection;
+    ///
+    /// let store = SqliteStore::new(Connection::open_in_memory().unwrap(), "orders").unwrap();
+    /// ```
+    pub fn new<S: Into<String>>(connection: Connection, name: S) -> rusqlite::Result<Self> {
+        let name = name.into();
+
+        connection.execute(
+            "CREATE TABLE IF NOT EXISTS light_id_counters (name TEXT PRIMARY KEY, value INTEGER NOT NULL)",
+            [],
+        )?;
+        connection.execute(
+            "INSERT OR IGNORE INTO light_id_counters (name, value) VALUES (?1, 0)",
+            params![name],
+        )?;
+
+        Ok(SqliteStore { connection, name })
+    }
+
+    /// Returns the name of the counter.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+fn to_io(error: rusqlite::Error) -> io::Error {
+    io::Error::other(error)
+}
+
+fn to_sql(value: usize) -> io::Result<i64> {
+    i64::try_from(value).map_err(|_| io::Error::other("counter overflow"))
+}
+
+fn from_sql(value: i64) -> io::Result<usize> {
+    usize::try_from(value).map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "invalid counter"))
+}
+
+impl CounterStore for SqliteStore {
+    fn load(&self) -> io::Result<usize> {
+        let value: i64 = self
+            .connection
+            .query_row(
+                "SELECT value FROM light_id_counters WHERE name = ?1",
+                params![self.name],
+                |row| row.get(0),
+            )
+            .map_err(to_io)?;
+
+        from_sql(value)
+    }
+
+    fn compare_and_swap(&self, current: usize, new: usize) -> io::Result<bool> {
+        let changed = self
+            .connection
+            .execute(
+                "UPDATE light_id_counters SET value = ?1 WHERE name = ?2 AND value = ?3",
+                params![to_sql(new)?, self.name, to_sql(current)?],
+            )
+            .map_err(to_io)?;
+
+        Ok(changed == 1)
+    }
+
+    fn reserve(&self, n: usize) -> io::Result<usize> {
+        let value: Option<i64> = self
+            .connection
+            .query_row(
+                "UPDATE light_id_counters SET value = value + ?1 WHERE name = ?2 RETURNING value - ?1",
+                params![to_sql(n)?, self.name],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(to_io)?;
+
+        from_sql(value.ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "missing counter"))?)
+    }
+}
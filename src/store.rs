@@ -0,0 +1,321 @@
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use fs2::FileExt;
+
+use crate::LightId;
+
+/// A shared counter, persisting the position of a sequence.
+/// ```
+/// use light_id::{CounterStore, MemoryStore};
+///
+/// let store = MemoryStore::new();
+///
+/// assert_eq!(0, store.reserve(10).unwrap());
+/// assert_eq!(10, store.load().unwrap());
+/// assert!(!store.compare_and_swap(0, 5).unwrap());
+/// assert!(store.compare_and_swap(10, 15).unwrap());
+/// ```
+pub trait CounterStore {
+    /// Returns the current value of the counter.
+    fn load(&self) -> io::Result<usize>;
+
+    /// Sets the counter to `new` if it still equals `current`, returning whether it was set.
+    fn compare_and_swap(&self, current: usize, new: usize) -> io::Result<bool>;
+
+    /// Advances the counter by `n`, returning its previous value.
+    fn reserve(&self, n: usize) -> io::Result<usize> {
+        loop {
+            let current = self.load()?;
+            let new = current.checked_add(n).ok_or_else(overflow)?;
+
+            if self.compare_and_swap(current, new)? {
+                return Ok(current);
+            }
+        }
+    }
+}
+
+fn overflow() -> io::Error {
+    io::Error::other("counter overflow")
+}
+
+/// A [`CounterStore`] kept in memory, shared between threads.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    value: AtomicUsize,
+}
+
+impl MemoryStore {
+    /// Creates a new [`MemoryStore`], starting at zero.
+    /// ```
+    /// use light_id::{CounterStore, MemoryStore};
+    ///
+    /// assert_eq!(0, MemoryStore::new().load().unwrap());
+    /// ```
+    pub fn new() -> Self {
+        MemoryStore::default()
+    }
+}
+
+impl CounterStore for MemoryStore {
+    fn load(&self) -> io::Result<usize> {
+        Ok(self.value.load(Ordering::SeqCst))
+    }
+
+    fn compare_and_swap(&self, current: usize, new: usize) -> io::Result<bool> {
+        Ok(self
+            .value
+            .compare_exchange(current, new, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok())
+    }
+
+    fn reserve(&self, n: usize) -> io::Result<usize> {
+        self.value
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |value| value.checked_add(n))
+            .map_err(|_| overflow())
+    }
+}
+
+/// A [`CounterStore`] kept in a text file, so that processes of the same host
+/// can share it. A missing file reads as zero, while an empty or invalid one is
+/// an error rather than a reset of the sequence.
+///
+/// Writes go to a temporary file renamed over the counter, so that a crash
+/// never leaves a partial value. They are serialized by a lock on a sibling
+/// file, with the `.lock` extension appended, that is never replaced.
+/// ```
+/// use light_id::{CounterStore, FileStore};
+///
+/// let dir = tempfile::tempdir().unwrap();
+/// let store = FileStore::new(dir.path().join("orders.counter"));
+///
+/// assert_eq!(0, store.reserve(10).unwrap());
+/// assert_eq!(10, FileStore::new(dir.path().join("orders.counter")).load().unwrap());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FileStore {
+    path: PathBuf,
+}
+
+impl FileStore {
+    /// Creates a new [`FileStore`], the file being created on the first write.
+    /// ```
+    /// use light_id::FileStore;
+    ///
+    /// let store = FileStore::new("orders.counter");
+    ///
+    /// assert_eq!("orders.counter", store.path().to_str().unwrap());
+    /// ```
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        FileStore {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Returns the path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the path of the counter with `extension` appended.
+    fn sibling(&self, extension: &str) -> PathBuf {
+        let mut path = OsString::from(self.path.as_os_str());
+
+        path.push(extension);
+        PathBuf::from(path)
+    }
+
+    /// Locks the counter and replaces its value with the one returned by `f`, if any.
+    /// Returns the previous value.
+    fn update<F: FnOnce(usize) -> io::Result<Option<usize>>>(&self, f: F) -> io::Result<usize> {
+        let lock = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(self.sibling(".lock"))?;
+
+        lock.lock_exclusive()?;
+
+        let current = self.load()?;
+
+        if let Some(new) = f(current)? {
+            self.write(new)?;
+        }
+
+        Ok(current)
+    }
+
+    /// Writes `value` to a temporary file and renames it over the counter.
+    fn write(&self, value: usize) -> io::Result<()> {
+        let temporary = self.sibling(".tmp");
+        let mut file = File::create(&temporary)?;
+
+        file.write_all(value.to_string().as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&temporary, &self.path)?;
+
+        // Persists the rename itself
+        #[cfg(unix)]
+        {
+            let directory = match self.path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent,
+                _ => Path::new("."),
+            };
+
+            File::open(directory)?.sync_all()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl CounterStore for FileStore {
+    fn load(&self) -> io::Result<usize> {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(error) => return Err(error),
+        };
+
+        content
+            .trim()
+            .parse()
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "invalid counter"))
+    }
+
+    fn compare_and_swap(&self, current: usize, new: usize) -> io::Result<bool> {
+        let previous = self.update(|value| Ok((value == current).then_some(new)))?;
+
+        Ok(previous == current)
+    }
+
+    fn reserve(&self, n: usize) -> io::Result<usize> {
+        self.update(|value| value.checked_add(n).map(Some).ok_or_else(overflow))
+    }
+}
+
+/// A generator whose position is kept in a [`CounterStore`], so that several
+/// generators, possibly in other processes, share the same sequence.
+///
+/// Positions are reserved by blocks: the ones left when the generator is
+/// dropped are never issued.
+/// ```
+/// use light_id::{LightId, MemoryStore, StoredLightId};
+///
+/// let mut generator = StoredLightId::new(LightId::new(), MemoryStore::new());
+///
+/// assert_eq!("0", generator.next().unwrap());
+/// assert_eq!("1", generator.next().unwrap());
+/// ```
+#[derive(Debug)]
+pub struct StoredLightId<S> {
+    generator: LightId,
+    store: S,
+    block: usize,
+    reserved: Range<usize>,
+}
+
+impl<S: CounterStore> StoredLightId<S> {
+    /// Creates a new [`StoredLightId`], formatting the ids with `generator`
+    /// and ignoring its own count.
+    /// ```
+    /// use light_id::{LightId, MemoryStore, StoredLightId};
+    ///
+    /// let generator = StoredLightId::new(LightId::from("abc"), MemoryStore::new());
+    /// ```
+    pub fn new(generator: LightId, store: S) -> Self {
+        StoredLightId {
+            generator,
+            store,
+            block: 1,
+            reserved: 0..0,
+        }
+    }
+
+    /// Sets the number of positions reserved at once, trading gaps in the
+    /// sequence for fewer accesses to the store.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    /// ```
+    /// use light_id::{CounterStore, LightId, MemoryStore, StoredLightId};
+    ///
+    /// let mut generator = StoredLightId::new(LightId::new(), MemoryStore::new());
+    ///
+    /// generator.block(100);
+    /// generator.next().unwrap();
+    ///
+    /// assert_eq!(100, generator.store().load().unwrap());
+    /// ```
+    pub fn block(&mut self, size: usize) -> &mut Self {
+        assert!(size > 0, "The block size must be positive");
+
+        self.block = size;
+        self
+    }
+
+    /// Reserves the next position and returns its id, skipping the positions
+    /// whose id is blocked by the [`Blocklist`](crate::Blocklist) of the generator.
+    /// Fails once the capacity of the generator is exceeded, rather than
+    /// panicking like [`LightId::nth`].
+    /// ```
+    /// use light_id::{LightId, MemoryStore, StoredLightId};
+    ///
+    /// let store = MemoryStore::new();
+    /// let mut generator = StoredLightId::new(LightId::new(), &store);
+    ///
+    /// assert_eq!("0", generator.next().unwrap());
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> io::Result<String> {
+        loop {
+            let n = match self.reserved.next() {
+                Some(n) => n,
+                None => {
+                    let start = self.store.reserve(self.block)?;
+
+                    self.reserved = start + 1..start + self.block;
+                    start
+                }
+            };
+
+            if self.generator.exceeds(n) {
+                return Err(io::Error::other("capacity exceeded"));
+            }
+
+            if !self.generator.is_blocked_at(n) {
+                return Ok(self.generator.nth(n));
+            }
+        }
+    }
+
+    /// Returns the generator formatting the ids.
+    pub fn generator(&self) -> &LightId {
+        &self.generator
+    }
+
+    /// Returns the store of the counter.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+}
+
+impl<S: CounterStore + ?Sized> CounterStore for &S {
+    fn load(&self) -> io::Result<usize> {
+        (**self).load()
+    }
+
+    fn compare_and_swap(&self, current: usize, new: usize) -> io::Result<bool> {
+        (**self).compare_and_swap(current, new)
+    }
+
+    fn reserve(&self, n: usize) -> io::Result<usize> {
+        (**self).reserve(n)
+    }
+}
@@ -0,0 +1,480 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{utils, Alphabet, ConfigError, LightId, ParseError, Preset};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Segment {
+    Literal(String),
+    Sequence,
+    Check,
+    Year,
+    Variable(String),
+}
+
+/// A generator of ids shaped by a template, such as `INV-{year}-{seq:6:0-9}-{check}`.
+///
+/// The placeholders are:
+/// - `{seq}`, `{seq:width}` or `{seq:width:alphabet}`: the sequence, filled from a
+///   [`LightId`] counter. The alphabet is either a preset name (`base58`) or a list of
+///   characters and ranges (`0-9A-F`), defaulting to [`DEFAULT_CHARACTERS`](crate::DEFAULT_CHARACTERS).
+/// - `{check}`: a check symbol of the sequence, computed with the Luhn mod N algorithm.
+/// - `{year}`: the current UTC year, unless set with [`Template::set`].
+/// - `{name}`: any other value, set with [`Template::set`].
+/// ```
+/// use light_id::Template;
+///
+/// let mut template = Template::new("INV-{year}-{seq:6:0-9}-{check}").unwrap();
+/// template.set("year", "2024");
+///
+/// assert_eq!("INV-2024-000000-0", template.next());
+/// assert_eq!("INV-2024-000001-8", template.next());
+///
+/// let parsed = template.parse("INV-2024-000001-8").unwrap();
+///
+/// assert_eq!(1, parsed.index);
+/// assert_eq!(Some("2024"), parsed.get("year"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Template {
+    segments: Vec<Segment>,
+    generator: LightId,
+    values: Vec<(String, String)>,
+}
+
+/// The components of an id parsed by a [`Template`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TemplateMatch {
+    /// The index of the sequence.
+    pub index: usize,
+    /// The raw text of each placeholder, in order.
+    pub fields: Vec<(String, String)>,
+}
+
+impl TemplateMatch {
+    /// Returns the raw text of a placeholder.
+    /// ```
+    /// use light_id::Template;
+    ///
+    /// let template = Template::new("{region}-{seq:4:0-9}").unwrap();
+    /// let parsed = template.parse("eu-0042").unwrap();
+    ///
+    /// assert_eq!(Some("eu"), parsed.get("region"));
+    /// assert_eq!(Some("0042"), parsed.get("seq"));
+    /// assert_eq!(42, parsed.index);
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl Template {
+    /// Creates a new [`Template`] from its pattern.
+    /// Literal braces are written `{{` and `}}`.
+    /// ```
+    /// use light_id::{ConfigError, Template};
+    ///
+    /// assert!(Template::new("ORD-{seq:8}").is_ok());
+    /// assert!(matches!(Template::new("ORD-{seq"), Err(ConfigError::InvalidTemplate(_))));
+    /// ```
+    pub fn new<S: AsRef<str>>(pattern: S) -> Result<Self, ConfigError> {
+        let invalid = |message: &str| ConfigError::InvalidTemplate(message.to_string());
+
+        let mut segments = vec![];
+        let mut generator = None;
+        let mut literal = String::new();
+        let mut chars = pattern.as_ref().chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '}' => return Err(invalid("unmatched '}'")),
+                '{' => {
+                    let mut placeholder = String::new();
+
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => placeholder.push(c),
+                            None => return Err(invalid("unmatched '{'")),
+                        }
+                    }
+
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                    }
+
+                    let mut parts = placeholder.split(':');
+
+                    let segment = match parts.next().unwrap_or_default() {
+                        "" => return Err(invalid("empty placeholder")),
+                        "seq" => {
+                            if generator.is_some() {
+                                return Err(invalid("more than one {seq}"));
+                            }
+
+                            let width = match parts.next() {
+                                Some(width) => width.parse().map_err(|_| invalid("invalid width"))?,
+                                None => 0,
+                            };
+
+                            let alphabet = match parts.next() {
+                                Some(spec) => parse_alphabet(spec)?,
+                                None => Alphabet::new(crate::DEFAULT_CHARACTERS),
+                            };
+
+                            if alphabet.len() < 2 {
+                                return Err(invalid("the alphabet needs at least 2 symbols"));
+                            }
+
+                            let mut sequence = LightId::from(alphabet);
+                            sequence.min(width);
+                            generator = Some(sequence);
+
+                            Segment::Sequence
+                        }
+                        "check" => Segment::Check,
+                        "year" => Segment::Year,
+                        name => Segment::Variable(name.to_string()),
+                    };
+
+                    if parts.next().is_some() {
+                        return Err(invalid("unexpected parameter"));
+                    }
+
+                    segments.push(segment);
+                }
+                c => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+
+        Ok(Template {
+            segments,
+            generator: generator.ok_or_else(|| invalid("missing {seq}"))?,
+            values: vec![],
+        })
+    }
+
+    /// Sets the value of a placeholder, or overrides the current year.
+    /// ```
+    /// use light_id::Template;
+    ///
+    /// let mut template = Template::new("{region}-{seq}").unwrap();
+    ///
+    /// template.set("region", "eu");
+    ///
+    /// assert_eq!("eu-0", template.current());
+    /// ```
+    pub fn set<N: AsRef<str>, V: AsRef<str>>(&mut self, name: N, value: V) -> &mut Self {
+        let name = name.as_ref().to_string();
+        let value = value.as_ref().to_string();
+
+        match self.values.iter_mut().find(|(n, _)| n == &name) {
+            Some(entry) => entry.1 = value,
+            None => self.values.push((name, value)),
+        }
+
+        self
+    }
+
+    /// Returns the counter filling the sequence, to skip or inspect it.
+    /// ```
+    /// use light_id::Template;
+    ///
+    /// let mut template = Template::new("#{seq:3:0-9}").unwrap();
+    ///
+    /// template.sequence().skip(41);
+    ///
+    /// assert_eq!("#041", template.current());
+    /// ```
+    pub fn sequence(&mut self) -> &mut LightId {
+        &mut self.generator
+    }
+
+    /// Increments the sequence by one and returns the id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a placeholder has no value.
+    /// ```
+    /// use light_id::Template;
+    ///
+    /// let mut template = Template::new("A{seq:2:0-9}").unwrap();
+    ///
+    /// assert_eq!("A00", template.next());
+    /// assert_eq!("A01", template.next());
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> String {
+        let id = self.current();
+        self.generator.increment();
+        id
+    }
+
+    /// Returns the current id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a placeholder has no value.
+    /// ```
+    /// use light_id::Template;
+    ///
+    /// let template = Template::new("A{seq:2:0-9}").unwrap();
+    ///
+    /// assert_eq!("A00", template.current());
+    /// ```
+    pub fn current(&self) -> String {
+        self.nth(self.generator.count())
+    }
+
+    /// Returns the id with the nth sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a placeholder has no value.
+    /// ```
+    /// use light_id::Template;
+    ///
+    /// let template = Template::new("A{seq:2:0-9}").unwrap();
+    ///
+    /// assert_eq!("A42", template.nth(42));
+    /// ```
+    pub fn nth(&self, n: usize) -> String {
+        let sequence = self.generator.nth(n);
+        let mut id = String::new();
+
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(literal) => id.push_str(literal),
+                Segment::Sequence => id.push_str(&sequence),
+                Segment::Check => {
+                    id.push_str(&self.generator.alphabet.symbols()[self.check_digit(&sequence)])
+                }
+                Segment::Year => match self.value("year") {
+                    Some(year) => id.push_str(year),
+                    None => id.push_str(&current_year().to_string()),
+                },
+                Segment::Variable(name) => match self.value(name) {
+                    Some(value) => id.push_str(value),
+                    None => panic!("Missing value for {{{}}}", name),
+                },
+            }
+        }
+
+        id
+    }
+
+    /// Parses an id into its components, validating its literals and check symbol.
+    /// The placeholders given a value with [`Template::set`] must have that value.
+    /// ```
+    /// use light_id::{ParseError, Template};
+    ///
+    /// let mut template = Template::new("INV-{year}-{seq:6:0-9}-{check}").unwrap();
+    ///
+    /// assert_eq!(1, template.parse("INV-2024-000001-8").unwrap().index);
+    /// assert_eq!(Err(ParseError::InvalidChecksum), template.parse("INV-2024-000001-7"));
+    /// assert_eq!(Err(ParseError::InvalidFormat), template.parse("ORD-2024-000001-8"));
+    ///
+    /// template.set("year", "2025");
+    ///
+    /// assert_eq!(Err(ParseError::InvalidFormat), template.parse("INV-2024-000001-8"));
+    /// ```
+    pub fn parse<S: AsRef<str>>(&self, id: S) -> Result<TemplateMatch, ParseError> {
+        let symbols: Vec<&str> = id.as_ref().graphemes(true).collect();
+        let mut spans = vec![];
+        let mut failed = vec![false; (self.segments.len() + 1) * (symbols.len() + 1)];
+
+        if !self.matches(&symbols, 0, 0, &mut spans, &mut failed) {
+            return Err(ParseError::InvalidFormat);
+        }
+
+        let mut fields = vec![];
+        let mut index = 0;
+        let mut sequence = String::new();
+        let mut check = None;
+
+        for (segment, (start, end)) in self.segments.iter().zip(spans) {
+            let text = symbols[start..end].concat();
+
+            match segment {
+                Segment::Literal(_) => continue,
+                Segment::Sequence => {
+                    index = self.generator.try_index(&text)?;
+                    sequence = text.clone();
+                    fields.push(("seq".to_string(), text));
+                }
+                Segment::Check => {
+                    check = Some(text.clone());
+                    fields.push(("check".to_string(), text));
+                }
+                Segment::Year => fields.push(("year".to_string(), text)),
+                Segment::Variable(name) => fields.push((name.clone(), text)),
+            }
+        }
+
+        if let Some(check) = check {
+            let digit = self.generator.alphabet.position(&check).flatten();
+
+            if digit != Some(self.check_digit(&sequence)) {
+                return Err(ParseError::InvalidChecksum);
+            }
+        }
+
+        Ok(TemplateMatch { index, fields })
+    }
+
+    fn value(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn check_digit(&self, sequence: &str) -> usize {
+        let alphabet = &self.generator.alphabet;
+        let digits: Vec<usize> = alphabet
+            .split(sequence)
+            .into_iter()
+            .filter_map(|symbol| alphabet.position(symbol).flatten())
+            .collect();
+
+        utils::check_digit(&digits, alphabet.len())
+    }
+
+    /// Matches the segments from `segment` against the symbols from `position`,
+    /// recording the span of each segment. The states known to fail are marked
+    /// in `failed`, so that each one is only tried once.
+    fn matches(
+        &self,
+        symbols: &[&str],
+        segment: usize,
+        position: usize,
+        spans: &mut Vec<(usize, usize)>,
+        failed: &mut [bool],
+    ) -> bool {
+        let Some(current) = self.segments.get(segment) else {
+            return position == symbols.len();
+        };
+
+        let state = segment * (symbols.len() + 1) + position;
+
+        if failed[state] {
+            return false;
+        }
+
+        let rest = &symbols[position..];
+        let alphabet = &self.generator.alphabet;
+        let in_alphabet = |symbol: &&str| matches!(alphabet.position(symbol), Some(Some(_)));
+        // The literals, and the placeholders given a value, match a single text
+        let fixed = match current {
+            Segment::Literal(literal) => Some(literal.as_str()),
+            Segment::Year => self.value("year"),
+            Segment::Variable(name) => self.value(name),
+            _ => None,
+        };
+
+        let ends: Vec<usize> = match (current, fixed) {
+            (_, Some(text)) => {
+                let text: Vec<&str> = text.graphemes(true).collect();
+
+                match rest.starts_with(&text) {
+                    true => vec![text.len()],
+                    false => vec![],
+                }
+            }
+            (Segment::Year, _) => {
+                let digits = rest
+                    .iter()
+                    .take_while(|s| s.len() == 1 && s.as_bytes()[0].is_ascii_digit())
+                    .count();
+
+                (1..=digits).rev().collect()
+            }
+            (Segment::Check, _) => match rest.first().is_some_and(in_alphabet) {
+                true => vec![1],
+                false => vec![],
+            },
+            (Segment::Sequence, _) => {
+                let valid = rest.iter().take_while(|s| in_alphabet(s)).count();
+                let min = std::cmp::max(self.generator.min_length, 1);
+
+                (min..=valid).rev().collect()
+            }
+            (Segment::Literal(_) | Segment::Variable(_), _) => (1..=rest.len()).rev().collect(),
+        };
+
+        for end in ends {
+            spans.push((position, position + end));
+
+            if self.matches(symbols, segment + 1, position + end, spans, failed) {
+                return true;
+            }
+
+            spans.pop();
+        }
+
+        failed[state] = true;
+        false
+    }
+}
+
+/// Expands an alphabet specification, either a preset name or characters and
+/// ranges such as `0-9A-F`.
+fn parse_alphabet(spec: &str) -> Result<Alphabet, ConfigError> {
+    if let Some(preset) = Preset::from_name(spec) {
+        return Ok(Alphabet::from(preset));
+    }
+
+    let chars: Vec<char> = spec.chars().collect();
+    let mut characters = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if i + 2 < chars.len() && chars[i + 1] == '-' {
+            if chars[i] > chars[i + 2] {
+                return Err(ConfigError::InvalidTemplate(format!("invalid range {:?}", &spec)));
+            }
+
+            characters.extend(chars[i]..=chars[i + 2]);
+            i += 3;
+        } else {
+            characters.push(chars[i]);
+            i += 1;
+        }
+    }
+
+    Ok(Alphabet::new(characters))
+}
+
+/// Returns the current UTC year.
+fn current_year() -> i64 {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() as i64);
+
+    // Civil from days, see http://howardhinnant.github.io/date_algorithms.html
+    let z = seconds.div_euclid(86400) + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+
+    yoe + era * 400 + i64::from(month <= 2)
+}
@@ -0,0 +1,412 @@
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::{CounterStore, FileStore};
+
+/// The longest request accepted, in bytes, the connection being closed beyond.
+const MAX_REQUEST: usize = 1024;
+
+/// How long reads and writes may block by default, on both sides.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The number of connections served at once by default.
+const DEFAULT_MAX_CONNECTIONS: usize = 256;
+
+/// Serves the counters of a directory over TCP, each counter being persisted
+/// in a [`FileStore`] so that restarts never reissue a position.
+///
+/// The protocol is line-based, each request receiving one response:
+/// - `LOAD <name>` answers `OK <value>`.
+/// - `CAS <name> <current> <new>` answers `OK 1` if the counter was set, `OK 0` otherwise.
+/// - `LEASE <name> <count>` advances the counter and answers `OK <start>`.
+///
+/// Failed requests answer `ERR <message>`. Names are made of ASCII
+/// alphanumeric characters, `-` and `_`. Requests longer than 1024 bytes
+/// answer `ERR request too long` and close the connection.
+///
+/// Each connection is served by its own thread, up to 256 at once, and is
+/// closed once it stays idle for 30 seconds.
+/// ```no_run
+/// use light_id::TicketServer;
+///
+/// let mut server = TicketServer::bind("127.0.0.1:7878", "counters").unwrap();
+///
+/// server.on_error(|error| eprintln!("{}", error));
+/// server.serve().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct TicketServer {
+    listener: TcpListener,
+    directory: PathBuf,
+    timeout: Duration,
+    max_connections: usize,
+    on_error: fn(&io::Error),
+}
+
+impl TicketServer {
+    /// Listens on `address`, keeping the counters in `directory`, created if needed.
+    /// ```
+    /// use light_id::TicketServer;
+    ///
+    /// let dir = tempfile::tempdir().unwrap();
+    /// let server = TicketServer::bind("127.0.0.1:0", dir.path()).unwrap();
+    ///
+    /// assert!(server.local_addr().unwrap().port() > 0);
+    /// ```
+    pub fn bind<A: ToSocketAddrs, P: AsRef<Path>>(address: A, directory: P) -> io::Result<Self> {
+        std::fs::create_dir_all(&directory)?;
+
+        Ok(TicketServer {
+            listener: TcpListener::bind(address)?,
+            directory: directory.as_ref().to_path_buf(),
+            timeout: DEFAULT_TIMEOUT,
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            on_error: |_| {},
+        })
+    }
+
+    /// Sets how long a connection may stay idle, or blocked on a write, before
+    /// being closed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timeout` is zero.
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use light_id::TicketServer;
+    ///
+    /// let dir = tempfile::tempdir().unwrap();
+    /// let mut server = TicketServer::bind("127.0.0.1:0", dir.path()).unwrap();
+    ///
+    /// server.timeout(Duration::from_secs(5));
+    /// ```
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        assert!(!timeout.is_zero(), "The timeout must be positive");
+
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the number of connections served at once. The ones beyond are
+    /// answered `ERR too many connections` and closed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    /// ```
+    /// use light_id::TicketServer;
+    ///
+    /// let dir = tempfile::tempdir().unwrap();
+    /// let mut server = TicketServer::bind("127.0.0.1:0", dir.path()).unwrap();
+    ///
+    /// server.max_connections(16);
+    /// ```
+    pub fn max_connections(&mut self, n: usize) -> &mut Self {
+        assert!(n > 0, "The number of connections must be positive");
+
+        self.max_connections = n;
+        self
+    }
+
+    /// Sets the function called with the errors of failed accepts and of
+    /// dropped connections, such as to log them. They are ignored by default.
+    /// ```
+    /// use light_id::TicketServer;
+    ///
+    /// let dir = tempfile::tempdir().unwrap();
+    /// let mut server = TicketServer::bind("127.0.0.1:0", dir.path()).unwrap();
+    ///
+    /// server.on_error(|error| eprintln!("ticket server: {}", error));
+    /// ```
+    pub fn on_error(&mut self, hook: fn(&io::Error)) -> &mut Self {
+        self.on_error = hook;
+        self
+    }
+
+    /// Returns the address the server listens on.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Accepts connections, serving each one in its own thread. Failed accepts,
+    /// such as when running out of file descriptors, are reported to the
+    /// [`TicketServer::on_error`] hook and retried.
+    pub fn serve(&self) -> io::Result<()> {
+        let connections = Arc::new(AtomicUsize::new(0));
+
+        for stream in self.listener.incoming() {
+            let result = match stream {
+                Ok(stream) => self.accept(stream, &connections),
+                Err(error) => {
+                    // Leaves time for file descriptors to be released
+                    thread::sleep(Duration::from_millis(100));
+                    Err(error)
+                }
+            };
+
+            if let Err(error) = result {
+                (self.on_error)(&error);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn accept(&self, mut stream: TcpStream, connections: &Arc<AtomicUsize>) -> io::Result<()> {
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+
+        if connections.fetch_add(1, Ordering::SeqCst) >= self.max_connections {
+            connections.fetch_sub(1, Ordering::SeqCst);
+            return stream.write_all(b"ERR too many connections\n");
+        }
+
+        let directory = self.directory.clone();
+        let connections = Arc::clone(connections);
+        let on_error = self.on_error;
+
+        thread::spawn(move || {
+            // The connection is dropped on errors, the client reconnecting if needed
+            if let Err(error) = handle(&directory, stream) {
+                on_error(&error);
+            }
+
+            connections.fetch_sub(1, Ordering::SeqCst);
+        });
+
+        Ok(())
+    }
+}
+
+fn handle(directory: &Path, stream: TcpStream) -> io::Result<()> {
+    let mut writer = stream.try_clone()?;
+    let mut reader = BufReader::new(stream);
+
+    loop {
+        let mut line = vec![];
+        let limit = MAX_REQUEST as u64 + 1;
+
+        if (&mut reader).take(limit).read_until(b'\n', &mut line)? == 0 {
+            return Ok(());
+        }
+
+        if line.last() == Some(&b'\n') {
+            line.pop();
+        } else if line.len() > MAX_REQUEST {
+            return writer.write_all(b"ERR request too long\n");
+        }
+
+        let request = String::from_utf8(line)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        let response = match respond(directory, request.trim_end_matches('\r')) {
+            Ok(value) => format!("OK {}\n", value),
+            Err(message) => format!("ERR {}\n", message),
+        };
+
+        writer.write_all(response.as_bytes())?;
+    }
+}
+
+fn respond(directory: &Path, request: &str) -> Result<usize, String> {
+    let parts: Vec<&str> = request.split_whitespace().collect();
+
+    let store = |name: &str| {
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+        if !valid {
+            return Err(format!("invalid name {:?}", name));
+        }
+
+        Ok(FileStore::new(directory.join(format!("{}.counter", name))))
+    };
+    let number = |value: &str| {
+        value
+            .parse::<usize>()
+            .map_err(|_| format!("invalid number {:?}", value))
+    };
+
+    match parts.as_slice() {
+        ["LOAD", name] => store(name)?.load().map_err(|e| e.to_string()),
+        ["CAS", name, current, new] => store(name)?
+            .compare_and_swap(number(current)?, number(new)?)
+            .map(usize::from)
+            .map_err(|e| e.to_string()),
+        ["LEASE", name, count] => store(name)?
+            .reserve(number(count)?)
+            .map_err(|e| e.to_string()),
+        _ => Err(format!("invalid request {:?}", request)),
+    }
+}
+
+/// A [`CounterStore`] leasing positions from a [`TicketServer`]. Combined with a
+/// [`StoredLightId`](crate::StoredLightId), it caches blocks of positions and
+/// fetches new ones when they run out, reconnecting if the server restarted.
+///
+/// Connecting, sending a request and waiting for its response each time out
+/// after 30 seconds, so that an unresponsive server fails the requests rather
+/// than blocking them for ever.
+/// ```no_run
+/// use light_id::{LightId, StoredLightId, TicketClient};
+///
+/// let client = TicketClient::connect("127.0.0.1:7878", "orders").unwrap();
+/// let mut generator = StoredLightId::new(LightId::new(), client);
+///
+/// generator.block(100);
+///
+/// println!("{}", generator.next().unwrap());
+/// ```
+#[derive(Debug)]
+pub struct TicketClient {
+    addresses: Vec<SocketAddr>,
+    name: String,
+    timeout: Duration,
+    connection: Mutex<Option<BufReader<TcpStream>>>,
+}
+
+impl TicketClient {
+    /// Connects to the server at `address`, using the counter called `name`.
+    /// ```
+    /// use light_id::{CounterStore, TicketClient, TicketServer};
+    ///
+    /// let dir = tempfile::tempdir().unwrap();
+    /// let server = TicketServer::bind("127.0.0.1:0", dir.path()).unwrap();
+    /// let address = server.local_addr().unwrap();
+    ///
+    /// std::thread::spawn(move || server.serve());
+    ///
+    /// let client = TicketClient::connect(address, "orders").unwrap();
+    ///
+    /// assert_eq!(0, client.reserve(10).unwrap());
+    /// assert_eq!(10, client.load().unwrap());
+    /// ```
+    pub fn connect<A: ToSocketAddrs, S: Into<String>>(address: A, name: S) -> io::Result<Self> {
+        let addresses = address.to_socket_addrs()?.collect();
+        let client = TicketClient {
+            addresses,
+            name: name.into(),
+            timeout: DEFAULT_TIMEOUT,
+            connection: Mutex::new(None),
+        };
+
+        *client.connection.lock().unwrap() = Some(client.open()?);
+        Ok(client)
+    }
+
+    /// Sets how long connecting, sending a request or waiting for its response
+    /// may take, the current connection being replaced on the next request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timeout` is zero.
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use light_id::{CounterStore, TicketClient, TicketServer};
+    ///
+    /// let dir = tempfile::tempdir().unwrap();
+    /// let server = TicketServer::bind("127.0.0.1:0", dir.path()).unwrap();
+    /// let address = server.local_addr().unwrap();
+    ///
+    /// std::thread::spawn(move || server.serve());
+    ///
+    /// let mut client = TicketClient::connect(address, "orders").unwrap();
+    ///
+    /// client.timeout(Duration::from_secs(1));
+    ///
+    /// assert_eq!(0, client.load().unwrap());
+    /// ```
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        assert!(!timeout.is_zero(), "The timeout must be positive");
+
+        self.timeout = timeout;
+        *self.connection.get_mut().unwrap() = None;
+        self
+    }
+
+    /// Returns the name of the counter.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn open(&self) -> io::Result<BufReader<TcpStream>> {
+        let mut error = io::Error::new(io::ErrorKind::InvalidInput, "no address to connect to");
+
+        for address in &self.addresses {
+            match TcpStream::connect_timeout(address, self.timeout) {
+                Ok(stream) => {
+                    stream.set_read_timeout(Some(self.timeout))?;
+                    stream.set_write_timeout(Some(self.timeout))?;
+
+                    return Ok(BufReader::new(stream));
+                }
+                Err(e) => error = e,
+            }
+        }
+
+        Err(error)
+    }
+
+    /// Sends a request, reconnecting once if the connection was lost.
+    fn request(&self, request: &str) -> io::Result<usize> {
+        let mut connection = self.connection.lock().unwrap();
+
+        let response = match connection.as_mut().map(|c| exchange(c, request)) {
+            Some(Ok(response)) => response,
+            _ => {
+                *connection = None;
+
+                let mut fresh = self.open()?;
+                let response = exchange(&mut fresh, request)?;
+
+                *connection = Some(fresh);
+                response
+            }
+        };
+
+        match response.split_once(' ') {
+            Some(("OK", value)) => value
+                .parse()
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "invalid response")),
+            Some(("ERR", message)) => Err(io::Error::other(message.to_string())),
+            _ => Err(io::Error::new(io::ErrorKind::InvalidData, "invalid response")),
+        }
+    }
+}
+
+fn exchange(connection: &mut BufReader<TcpStream>, request: &str) -> io::Result<String> {
+    connection
+        .get_mut()
+        .write_all(format!("{}\n", request).as_bytes())?;
+
+    let mut response = String::new();
+
+    if connection.read_line(&mut response)? == 0 {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+
+    Ok(response.trim_end().to_string())
+}
+
+impl CounterStore for TicketClient {
+    fn load(&self) -> io::Result<usize> {
+        self.request(&format!("LOAD {}", self.name))
+    }
+
+    fn compare_and_swap(&self, current: usize, new: usize) -> io::Result<bool> {
+        Ok(self.request(&format!("CAS {} {} {}", self.name, current, new))? == 1)
+    }
+
+    fn reserve(&self, n: usize) -> io::Result<usize> {
+        self.request(&format!("LEASE {} {}", self.name, n))
+    }
+}
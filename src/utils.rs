@@ -1,35 +1,201 @@
-pub fn parse_id(id: &str, chars: &Vec<char>) -> usize {
-    let mut status = 0;
+use std::cmp::Ordering;
 
-    for (index, char) in id.chars().rev().enumerate() {
-        status += chars
-            .iter()
-            .position(|i| i == &char)
-            .expect("Invalid character")
-            * chars.len().pow(index.try_into().unwrap());
+use crate::{Alphabet, ParseError};
+
+pub fn parse_id(id: &str, alphabet: &Alphabet) -> Result<usize, ParseError> {
+    from_digits(&parse_digits(id, alphabet)?, alphabet.len())
+}
+
+/// Returns the value of each symbol of an id, skipping the ignored ones.
+pub fn parse_digits(id: &str, alphabet: &Alphabet) -> Result<Vec<usize>, ParseError> {
+    let mut digits = vec![];
+
+    for symbol in alphabet.split(id) {
+        let position = alphabet
+            .position(symbol)
+            .ok_or_else(|| ParseError::invalid_symbol(symbol))?;
+
+        digits.extend(position);
     }
 
+    Ok(digits)
+}
+
+/// Returns the number made of `digits`, most significant first.
+pub fn from_digits(digits: &[usize], base: usize) -> Result<usize, ParseError> {
+    digits
+        .iter()
+        .try_fold(0, |status, digit| push_digit(status, *digit, base))
+}
+
+/// Appends a digit to the right of `status`, in the given base.
+pub fn push_digit(status: usize, digit: usize, base: usize) -> Result<usize, ParseError> {
     status
+        .checked_mul(base)
+        .and_then(|s| s.checked_add(digit))
+        .ok_or(ParseError::Overflow)
 }
 
-pub fn format_id(id: &usize, min: &usize, chars: &Vec<char>) -> String {
-    let mut current = String::new();
+/// Returns the check digit of `digits` in the given base, using the Luhn mod N algorithm.
+pub fn check_digit(digits: &[usize], base: usize) -> usize {
+    let mut sum = 0;
+
+    for (index, digit) in digits.iter().rev().enumerate() {
+        let addend = if index % 2 == 0 { digit * 2 } else { *digit };
+
+        sum += addend / base + addend % base;
+    }
+
+    (base - sum % base) % base
+}
+
+pub fn format_id(id: &usize, min: &usize, alphabet: &Alphabet) -> String {
+    digits(id, min, alphabet).join(alphabet.separator())
+}
+
+/// Returns the symbols of an id, most significant first.
+pub fn digits<'a>(id: &usize, min: &usize, alphabet: &'a Alphabet) -> Vec<&'a str> {
+    digits_at(id, min, |_| alphabet)
+}
+
+/// Returns the symbols of an id, most significant first, `alphabet_at` giving
+/// the alphabet of each position, counted from the end of the id.
+pub fn digits_at<'a, F>(id: &usize, min: &usize, alphabet_at: F) -> Vec<&'a str>
+where
+    F: Fn(usize) -> &'a Alphabet,
+{
+    let mut digits = vec![];
 
     let mut remaining: usize = *id;
 
     loop {
-        current.push(chars[remaining % chars.len()]);
+        let symbols = alphabet_at(digits.len()).symbols();
 
-        remaining = remaining / chars.len();
+        digits.push(symbols[remaining % symbols.len()].as_str());
+
+        remaining /= symbols.len();
 
         if remaining == 0 {
             break;
         }
     }
 
-    while &current.len() < min {
-        current.push(chars[0]);
+    while &digits.len() < min {
+        digits.push(alphabet_at(digits.len()).symbols()[0].as_str());
+    }
+
+    digits.reverse();
+    digits
+}
+
+/// Returns the digits of `n` in the given base, most significant first, without leading zeros.
+pub fn to_digits(mut n: usize, base: usize) -> Vec<usize> {
+    let mut digits = vec![];
+
+    while n > 0 {
+        digits.push(n % base);
+        n /= base;
+    }
+
+    digits.reverse();
+    digits
+}
+
+/// Removes the leading zeros of `digits`.
+pub fn trim_digits(digits: &[usize]) -> &[usize] {
+    let zeros = digits.iter().take_while(|digit| **digit == 0).count();
+
+    &digits[zeros..]
+}
+
+/// Compares two numbers made of digits, most significant first.
+pub fn compare_digits(a: &[usize], b: &[usize]) -> Ordering {
+    let (a, b) = (trim_digits(a), trim_digits(b));
+
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Returns `a + b`, both made of digits in the given base.
+pub fn add_digits(a: &[usize], b: &[usize], base: usize) -> Vec<usize> {
+    let mut sum = vec![];
+    let mut carry = 0;
+    let (mut a, mut b) = (a.iter().rev(), b.iter().rev());
+
+    loop {
+        let (x, y) = (a.next(), b.next());
+
+        if x.is_none() && y.is_none() && carry == 0 {
+            break;
+        }
+
+        let total = x.unwrap_or(&0) + y.unwrap_or(&0) + carry;
+
+        sum.push(total % base);
+        carry = total / base;
+    }
+
+    sum.reverse();
+    trim_digits(&sum).to_vec()
+}
+
+/// Returns `a - b`, both made of digits in the given base, or `None` if `b` is greater.
+pub fn sub_digits(a: &[usize], b: &[usize], base: usize) -> Option<Vec<usize>> {
+    if compare_digits(a, b) == Ordering::Less {
+        return None;
+    }
+
+    let mut difference = vec![];
+    let mut borrow = 0;
+    let mut b = b.iter().rev();
+
+    for x in a.iter().rev() {
+        let y = b.next().unwrap_or(&0) + borrow;
+
+        if *x >= y {
+            difference.push(x - y);
+            borrow = 0;
+        } else {
+            difference.push(x + base - y);
+            borrow = 1;
+        }
+    }
+
+    difference.reverse();
+    Some(trim_digits(&difference).to_vec())
+}
+
+/// Returns `a * m`, `a` being made of digits in the given base.
+pub fn mul_digits(a: &[usize], m: usize, base: usize) -> Vec<usize> {
+    let mut product = vec![];
+    let mut carry: u128 = 0;
+
+    for digit in a.iter().rev() {
+        let total = *digit as u128 * m as u128 + carry;
+
+        product.push((total % base as u128) as usize);
+        carry = total / base as u128;
+    }
+
+    while carry > 0 {
+        product.push((carry % base as u128) as usize);
+        carry /= base as u128;
+    }
+
+    product.reverse();
+    trim_digits(&product).to_vec()
+}
+
+/// Returns the quotient and the remainder of `a / d`, `a` being made of digits in the given base.
+pub fn div_digits(a: &[usize], d: usize, base: usize) -> (Vec<usize>, usize) {
+    let mut quotient = vec![];
+    let mut remainder: u128 = 0;
+
+    for digit in a {
+        let current = remainder * base as u128 + *digit as u128;
+
+        quotient.push((current / d as u128) as usize);
+        remainder = current % d as u128;
     }
 
-    current.chars().rev().collect()
+    (trim_digits(&quotient).to_vec(), remainder as usize)
 }
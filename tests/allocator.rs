@@ -0,0 +1,95 @@
+use std::collections::BTreeSet;
+
+use light_id::{IdAllocator, LightId, ParseError};
+
+#[test]
+fn smallest_free () {
+  let mut allocator = IdAllocator::new(LightId::new());
+
+  for _ in 0..10 {
+    allocator.allocate();
+  }
+
+  allocator.release("7");
+  allocator.release("3");
+  allocator.release("4");
+
+  assert_eq!(Some("3".to_string()), allocator.allocate());
+  assert_eq!(Some("4".to_string()), allocator.allocate());
+  assert_eq!(Some("7".to_string()), allocator.allocate());
+  assert_eq!(Some("a".to_string()), allocator.allocate());
+}
+
+#[test]
+fn stays_short () {
+  let mut allocator = IdAllocator::new(LightId::new());
+  let mut ids = vec![];
+
+  for _ in 0..62 {
+    ids.push(allocator.allocate().unwrap());
+  }
+
+  for _ in 0..1000 {
+    let id = ids.remove(0);
+
+    assert!(allocator.release(&id));
+    ids.push(allocator.allocate().unwrap());
+  }
+
+  assert!(ids.iter().all(|id| id.len() == 1));
+}
+
+#[test]
+fn shrinks () {
+  let mut allocator = IdAllocator::new(LightId::new());
+
+  for _ in 0..10 {
+    allocator.allocate();
+  }
+
+  for id in ["9", "5", "7", "8", "6"] {
+    allocator.release(id);
+  }
+
+  assert_eq!(0, allocator.free_ranges());
+  assert_eq!(1.0, allocator.utilization());
+  assert_eq!(Some("5".to_string()), allocator.allocate());
+}
+
+#[test]
+fn matches_model () {
+  let mut allocator = IdAllocator::new(LightId::new());
+  let mut model = BTreeSet::new();
+  let mut seed: u64 = 42;
+
+  for _ in 0..5000 {
+    seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+
+    if seed >> 62 == 0 {
+      let n = (seed >> 32) as usize % 200;
+      let id = allocator.generator().nth(n);
+
+      assert_eq!(model.remove(&n), allocator.release(&id));
+    } else {
+      let id = allocator.allocate().unwrap();
+      let smallest = (0..).find(|n| !model.contains(n)).unwrap();
+
+      assert_eq!(allocator.generator().nth(smallest), id);
+      model.insert(smallest);
+    }
+
+    assert_eq!(model.len(), allocator.allocated());
+  }
+
+  for n in 0..300 {
+    assert_eq!(model.contains(&n), allocator.is_allocated(allocator.generator().nth(n)));
+  }
+}
+
+#[test]
+fn invalid_ids () {
+  let mut allocator = IdAllocator::new(LightId::from("abc"));
+
+  assert_eq!(Err(ParseError::InvalidCharacter('z')), allocator.try_release("z"));
+  assert!(!allocator.is_allocated("z"));
+}
@@ -0,0 +1,66 @@
+use light_id::{Alphabet, IdSwitcher, LightId, Preset};
+
+#[test]
+fn case_insensitive () {
+  let mut alphabet = Alphabet::new("abc");
+  alphabet.case_insensitive(true);
+
+  let gen = LightId::from(alphabet);
+
+  assert_eq!(gen.index("cab"), gen.index("CaB"));
+}
+
+#[test]
+fn alias () {
+  let mut alphabet = Alphabet::new("0123456789");
+  alphabet.alias('O', '0').alias('l', '1');
+
+  let gen = LightId::from(alphabet);
+
+  assert_eq!(101, gen.index("lOl"));
+}
+
+#[test]
+fn ignore () {
+  let mut alphabet = Alphabet::new("abc");
+  alphabet.ignore('-').ignore(' ');
+
+  let gen = LightId::from(alphabet);
+
+  assert_eq!(gen.index("bacab"), gen.index("ba-ca b"));
+}
+
+#[test]
+#[should_panic]
+fn case_sensitive_by_default () {
+  let gen = LightId::from("abc");
+
+  gen.index("A");
+}
+
+#[test]
+fn crockford () {
+  let gen = LightId::with_preset(Preset::Crockford);
+  let expected = gen.index("10");
+
+  for id in ["1O", "lo", "Io", "i-0", "L-o"] {
+    assert_eq!(expected, gen.index(id), "{}", id);
+  }
+}
+
+#[test]
+fn crockford_switch () {
+  let switch = IdSwitcher::new(Preset::Crockford, Preset::Base10);
+
+  assert_eq!("1023", switch.switch("zz"));
+  assert_eq!("1023", switch.switch("Z-z"));
+}
+
+#[test]
+fn formatting_unchanged () {
+  let mut gen = LightId::with_preset(Preset::Crockford);
+
+  gen.skip(1023);
+
+  assert_eq!("ZZ", gen.current());
+}
@@ -0,0 +1,79 @@
+use light_id::{LightId, ParseError, Preset};
+
+#[test]
+fn consistent_with_nth () {
+  let generator = LightId::new();
+
+  for i in (0..100000).step_by(331) {
+    let id = generator.nth(i);
+
+    assert_eq!(generator.nth(i + 500), generator.add(&id, 500));
+    assert_eq!(generator.nth(i + 1), generator.succ(&id));
+    assert_eq!(generator.nth(i / 2), generator.sub(&id, i - i / 2));
+    assert_eq!(i, generator.distance(&id, generator.nth(0)));
+  }
+}
+
+#[test]
+fn long_ids () {
+  let generator = LightId::with_preset(Preset::Base2);
+  let max = "1".repeat(200);
+
+  assert_eq!(format!("1{}", "0".repeat(200)), generator.succ(&max));
+  assert_eq!(format!("{}0", "1".repeat(199)), generator.pred(&max));
+  assert_eq!(Err(ParseError::Overflow), generator.try_index(&max));
+  assert_eq!(max, generator.max_id(&max, "1"));
+  assert_eq!(3, generator.distance(&max, generator.add(&max, 3)));
+}
+
+#[test]
+fn formatted () {
+  let generator = LightId::builder()
+    .alphabet(Preset::Crockford)
+    .min(6)
+    .prefix("ord_")
+    .group(3, "-")
+    .checksum(true)
+    .build()
+    .unwrap();
+
+  let id = generator.nth(1000);
+
+  assert_eq!(generator.nth(1500), generator.add(&id, 500));
+  assert_eq!(generator.nth(999), generator.pred(&id));
+  assert_eq!(Err(ParseError::InvalidFormat), generator.try_succ("1000"));
+}
+
+#[test]
+fn step_and_offset () {
+  let mut generator = LightId::from("0123456789");
+
+  generator.step(2).offset(1);
+
+  assert_eq!("11", generator.add("1", 5));
+  assert_eq!("3", generator.pred("5"));
+  assert_eq!(5, generator.distance("1", "11"));
+  assert_eq!(Err(ParseError::OutOfSequence), generator.try_succ("4"));
+  assert_eq!(Err(ParseError::Underflow), generator.try_pred("1"));
+}
+
+#[test]
+fn descending () {
+  let mut generator = LightId::from("0123456789");
+
+  generator.max(3).descending(true);
+
+  assert_eq!("998", generator.succ("999"));
+  assert_eq!("000", generator.add("999", 999));
+  assert_eq!(Err(ParseError::Overflow), generator.try_add("999", 1000));
+  assert_eq!("999", generator.min_id("000", "999"));
+}
+
+#[test]
+fn min_and_max () {
+  let generator = LightId::from("abcdef");
+
+  assert_eq!("f", generator.min_id("ba", "f"));
+  assert_eq!("ba", generator.max_id("ba", "f"));
+  assert_eq!("ba", generator.max_id("ba", "ba"));
+}
@@ -0,0 +1,132 @@
+#![cfg(feature = "async")]
+
+use std::collections::HashSet;
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use light_id::{AsyncCounterStore, AsyncLightId, Blocking, FileStore, LightId, MemoryStore};
+
+/// A slow store, counting the reservations
+#[derive(Default)]
+struct SlowStore {
+  value: AtomicUsize,
+  reservations: AtomicUsize,
+}
+
+impl AsyncCounterStore for SlowStore {
+  async fn load (&self) -> io::Result<usize> {
+    Ok(self.value.load(Ordering::SeqCst))
+  }
+
+  async fn compare_and_swap (&self, current: usize, new: usize) -> io::Result<bool> {
+    Ok(self.value.compare_exchange(current, new, Ordering::SeqCst, Ordering::SeqCst).is_ok())
+  }
+
+  async fn reserve (&self, n: usize) -> io::Result<usize> {
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    self.reservations.fetch_add(1, Ordering::SeqCst);
+    Ok(self.value.fetch_add(n, Ordering::SeqCst))
+  }
+}
+
+#[tokio::test]
+async fn sequence () {
+  let mut generator = AsyncLightId::new(LightId::from("0123456789"), MemoryStore::new());
+
+  generator.block(4);
+
+  for i in 0..20 {
+    assert_eq!(i.to_string(), generator.next().await.unwrap());
+  }
+}
+
+#[tokio::test]
+async fn prefetch () {
+  let mut generator = AsyncLightId::new(LightId::new(), SlowStore::default());
+
+  generator.block(10).prefetch(5);
+
+  for _ in 0..6 {
+    generator.next().await.unwrap();
+  }
+
+  assert_eq!(1, generator.store().reservations.load(Ordering::SeqCst));
+
+  // The next block is fetched in the background
+  tokio::time::sleep(Duration::from_millis(100)).await;
+
+  assert_eq!(2, generator.store().reservations.load(Ordering::SeqCst));
+  assert_eq!(20, generator.store().value.load(Ordering::SeqCst));
+
+  for i in 6..20 {
+    assert_eq!(generator.generator().nth(i), generator.next().await.unwrap());
+  }
+}
+
+#[tokio::test]
+async fn default_prefetch () {
+  let mut generator = AsyncLightId::new(LightId::new(), SlowStore::default());
+
+  for i in 0..5 {
+    assert_eq!(i.to_string(), generator.next().await.unwrap());
+
+    // The next position is fetched in the background
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert_eq!(i + 2, generator.store().reservations.load(Ordering::SeqCst));
+  }
+}
+
+#[tokio::test]
+async fn default_reserve () {
+  struct CasOnly (MemoryStore);
+
+  impl AsyncCounterStore for CasOnly {
+    async fn load (&self) -> io::Result<usize> {
+      AsyncCounterStore::load(&self.0).await
+    }
+
+    async fn compare_and_swap (&self, current: usize, new: usize) -> io::Result<bool> {
+      AsyncCounterStore::compare_and_swap(&self.0, current, new).await
+    }
+  }
+
+  let store = CasOnly(MemoryStore::new());
+
+  assert_eq!(0, store.reserve(5).await.unwrap());
+  assert_eq!(5, store.reserve(5).await.unwrap());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn shared_file () {
+  let dir = tempfile::tempdir().unwrap();
+  let store = Blocking::new(FileStore::new(dir.path().join("counter")));
+  let mut handles = vec![];
+
+  for i in 0..4 {
+    let store = store.clone();
+
+    handles.push(tokio::spawn(async move {
+      let mut generator = AsyncLightId::new(LightId::new(), store);
+      let mut ids = vec![];
+
+      generator.block(i * 5 + 2);
+
+      for _ in 0..100 {
+        ids.push(generator.next().await.unwrap());
+      }
+      ids
+    }));
+  }
+
+  let mut ids = HashSet::new();
+
+  for handle in handles {
+    for id in handle.await.unwrap() {
+      assert!(ids.insert(id));
+    }
+  }
+
+  assert_eq!(400, ids.len());
+}
@@ -0,0 +1,171 @@
+use light_id::{Blocklist, ConfigError, IdAllocator, LightId, LightIdBuilder, MemoryStore, StoredLightId};
+
+#[test]
+fn skips_substrings () {
+  let mut blocklist = Blocklist::new();
+  blocklist.substrings(["a", "1"]);
+
+  let mut generator = LightId::new();
+  generator.blocklist(blocklist);
+
+  let ids: Vec<String> = (0..200).map(|_| generator.next()).collect();
+
+  assert!(ids.iter().all(|id| !id.contains('a') && !id.contains('1')));
+  assert_eq!("0", ids[0]);
+  assert_eq!("2", ids[1]);
+}
+
+#[test]
+fn skips_exact () {
+  let mut blocklist = Blocklist::new();
+  blocklist.exact(["2", "3"]);
+
+  let mut generator = LightId::new();
+  generator.blocklist(blocklist);
+
+  assert_eq!(vec!["0", "1", "4", "5"], (0..4).map(|_| generator.next()).collect::<Vec<_>>());
+  assert_eq!(6, generator.count());
+}
+
+#[test]
+fn keeps_positions () {
+  let mut blocklist = Blocklist::new();
+  blocklist.substrings(["b"]);
+
+  let mut generator = LightId::from("abc");
+  generator.blocklist(blocklist);
+
+  while generator.count() < 20 {
+    let id = generator.next();
+
+    assert_eq!(id, generator.nth(generator.index(&id)));
+    assert_eq!(generator.count() - 1, generator.index(&id));
+  }
+}
+
+#[test]
+fn matches_symbols () {
+  let mut blocklist = Blocklist::new();
+  blocklist.substrings(["bb"]);
+
+  let mut generator = LightIdBuilder::new()
+    .alphabet("ab")
+    .prefix("b")
+    .group(1, "-")
+    .blocklist(blocklist)
+    .build()
+    .unwrap();
+
+  assert_eq!(vec!["ba", "bb", "bb-a", "bb-a-a"], (0..4).map(|_| generator.next()).collect::<Vec<_>>());
+}
+
+#[test]
+fn blocked_format () {
+  let mut blocklist = Blocklist::new();
+  blocklist.substrings(["ASS"]).case_insensitive(true);
+
+  let result = LightIdBuilder::new().prefix("class_").blocklist(blocklist).build();
+
+  assert_eq!(Err(ConfigError::BlockedFormat), result);
+}
+
+#[test]
+#[should_panic]
+fn blocked_prefix () {
+  let mut blocklist = Blocklist::new();
+  blocklist.substrings(["ass"]);
+
+  LightId::new().blocklist(blocklist).prefix("class_");
+}
+
+#[test]
+fn blocked_len () {
+  let mut blocklist = Blocklist::new();
+  blocklist.exact(["c"]);
+
+  let mut generator = LightId::from("abc");
+  generator.blocklist(blocklist).skip(2);
+
+  assert_eq!("ba", generator.current());
+  assert_eq!(2, generator.len());
+}
+
+#[test]
+fn stored_and_allocated () {
+  let mut blocklist = Blocklist::new();
+  blocklist.exact(["1", "3"]);
+
+  let mut generator = LightId::new();
+  generator.blocklist(blocklist);
+
+  let store = MemoryStore::new();
+  let mut stored = StoredLightId::new(generator.clone(), &store);
+  stored.block(2);
+
+  assert_eq!(vec!["0", "2", "4"], (0..3).map(|_| stored.next().unwrap()).collect::<Vec<_>>());
+
+  let mut allocator = IdAllocator::new(generator);
+
+  assert_eq!(vec!["0", "2", "4"], (0..3).filter_map(|_| allocator.allocate()).collect::<Vec<_>>());
+  assert!(!allocator.is_allocated("1"));
+  assert!(!allocator.release("3"));
+  assert!(allocator.release("2"));
+  assert_eq!(Some("2".to_string()), allocator.allocate());
+}
+
+#[test]
+fn overlapping_patterns () {
+  let mut blocklist = Blocklist::new();
+  blocklist.substrings(["abcd", "bce", "c"]);
+
+  assert!(blocklist.is_blocked("zabce"));
+  assert!(blocklist.is_blocked("xxc"));
+  assert!(!blocklist.is_blocked("abdab"));
+
+  let mut blocklist = Blocklist::new();
+  blocklist.substrings(["aab"]);
+
+  assert!(blocklist.is_blocked("aaab"));
+  assert!(!blocklist.is_blocked("abab"));
+}
+
+#[test]
+fn case_insensitive () {
+  let mut blocklist = Blocklist::new();
+  blocklist.substrings(["Ab"]).exact(["ZZ"]);
+
+  assert!(!blocklist.is_blocked("xaBx"));
+  assert!(!blocklist.is_blocked("zz"));
+
+  blocklist.case_insensitive(true);
+
+  assert!(blocklist.is_blocked("xaBx"));
+  assert!(blocklist.is_blocked("zz"));
+}
+
+#[test]
+fn large_list () {
+  let words: Vec<String> = (0..10_000).map(|n| format!("w{}q", n)).collect();
+  let mut blocklist = Blocklist::new();
+  blocklist.substrings(&words);
+
+  assert!(blocklist.is_blocked("xxw9999qxx"));
+  assert!(!blocklist.is_blocked("w10000"));
+  assert!(!blocklist.is_empty());
+}
+
+#[test]
+fn large_exact_case_insensitive () {
+  let ids: Vec<String> = (0..100_000).map(|n| format!("Id{}", n)).collect();
+  let mut blocklist = Blocklist::new();
+  blocklist.exact(&ids).case_insensitive(true);
+
+  assert!(blocklist.is_blocked("iD99999"));
+  assert!(blocklist.is_blocked("ID0"));
+  assert!(!blocklist.is_blocked("id100000"));
+
+  blocklist.case_insensitive(false);
+
+  assert!(blocklist.is_blocked("Id42"));
+  assert!(!blocklist.is_blocked("id42"));
+}
@@ -0,0 +1,120 @@
+use light_id::{ConfigError, LightId, LightIdBuilder, ParseError, Preset};
+
+#[test]
+fn default () {
+  assert_eq!(LightId::new(), LightIdBuilder::new().build().unwrap());
+}
+
+#[test]
+fn invalid_alphabet () {
+  assert_eq!(Err(ConfigError::AlphabetTooSmall), LightIdBuilder::new().alphabet("a").build());
+  assert_eq!(Err(ConfigError::AlphabetTooSmall), LightIdBuilder::new().alphabet("").build());
+  assert_eq!(
+    Err(ConfigError::DuplicateSymbol("b".to_string())),
+    LightIdBuilder::new().alphabet("abcb").build()
+  );
+}
+
+#[test]
+fn invalid_length () {
+  assert_eq!(Err(ConfigError::InvalidLength), LightIdBuilder::new().max(0).build());
+  assert_eq!(Err(ConfigError::InvalidLength), LightIdBuilder::new().min(5).max(4).build());
+  assert!(LightIdBuilder::new().min(4).max(4).build().is_ok());
+}
+
+#[test]
+fn invalid_step () {
+  assert_eq!(Err(ConfigError::ZeroStep), LightIdBuilder::new().step(0).build());
+}
+
+#[test]
+fn invalid_start () {
+  assert_eq!(
+    Err(ConfigError::StartOutOfRange),
+    LightIdBuilder::new().alphabet("01").max(3).offset(8).build()
+  );
+  assert_eq!(
+    Err(ConfigError::StartOutOfRange),
+    LightIdBuilder::new().alphabet("01").max(3).step(3).start(3).build()
+  );
+  assert_eq!(
+    Err(ConfigError::StartOutOfRange),
+    LightIdBuilder::new().alphabet("01").max(3).start(8).build()
+  );
+  assert!(LightIdBuilder::new().alphabet("01").max(3).start(7).build().is_ok());
+}
+
+#[test]
+fn conflicting_separator () {
+  assert_eq!(
+    Err(ConfigError::ConflictingSeparator),
+    LightIdBuilder::new().group(2, "a").build()
+  );
+  assert_eq!(
+    Err(ConfigError::ConflictingSeparator),
+    LightIdBuilder::new().alphabet(Preset::Base16).group(2, "A").build()
+  );
+  assert!(LightIdBuilder::new().alphabet(Preset::Crockford).group(2, "-").build().is_ok());
+}
+
+#[test]
+fn start_and_step () {
+  let mut generator = LightIdBuilder::new().alphabet("0123456789").start(2).step(10).offset(5).build().unwrap();
+
+  assert_eq!("25", generator.next());
+  assert_eq!("35", generator.next());
+  assert_eq!("45", generator.next());
+  assert_eq!(2, generator.index("25"));
+}
+
+#[test]
+#[should_panic(expected = "Capacity exceeded")]
+fn max_length () {
+  let mut generator = LightIdBuilder::new().alphabet("01").max(2).build().unwrap();
+
+  assert_eq!(Some(4), generator.capacity());
+
+  for _ in 0..5 {
+    generator.next();
+  }
+}
+
+#[test]
+fn checksum () {
+  let generator = LightIdBuilder::new().alphabet("0123456789").checksum(true).build().unwrap();
+
+  assert_eq!("42", generator.nth(4));
+  assert_eq!(Ok(4), generator.try_index("42"));
+  assert_eq!(Err(ParseError::InvalidChecksum), generator.try_index("43"));
+}
+
+#[test]
+fn checksum_len () {
+  let mut generator = LightIdBuilder::new().alphabet("0123456789").max(2).checksum(true).build().unwrap();
+
+  generator.skip(42);
+
+  assert_eq!("422", generator.current());
+  assert_eq!(2, generator.len());
+
+  generator.skip(99);
+
+  assert_eq!("992", generator.current());
+  assert_eq!(2, generator.len());
+}
+
+#[test]
+fn formatting () {
+  let generator = LightIdBuilder::new()
+    .alphabet(Preset::Crockford)
+    .min(8)
+    .prefix("ord_")
+    .suffix("!")
+    .group(4, "-")
+    .start(1000)
+    .build()
+    .unwrap();
+
+  assert_eq!("ord_0000-00Z8!", generator.current());
+  assert_eq!(1000, generator.index("ord_0000-00z8!"));
+}
@@ -0,0 +1,98 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run (args: &[&str], stdin: &str) -> Output {
+  let mut child = Command::new(env!("CARGO_BIN_EXE_light-id"))
+    .args(args)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .unwrap();
+
+  // The command may exit before reading its input
+  let _ = child.stdin.take().unwrap().write_all(stdin.as_bytes());
+  child.wait_with_output().unwrap()
+}
+
+fn stdout (output: &Output) -> String {
+  String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn gen () {
+  let output = run(&["gen", "--alphabet", "abc", "--count", "4", "--start", "2", "--min=2"], "");
+
+  assert!(output.status.success());
+  assert_eq!("ac\nba\nbb\nbc\n", stdout(&output));
+}
+
+#[test]
+fn convert () {
+  let output = run(&["convert", "--from", "base10", "--to", "base16", "255", "4096"], "");
+
+  assert!(output.status.success());
+  assert_eq!("ff\n1000\n", stdout(&output));
+}
+
+#[test]
+fn convert_stdin () {
+  let output = run(&["convert", "--from", "01", "--to", "base10"], "1\n10\n\n1100100\n");
+
+  assert!(output.status.success());
+  assert_eq!("1\n2\n100\n", stdout(&output));
+}
+
+#[test]
+fn index () {
+  let output = run(&["index", "--alphabet", "crockford", "1O", "zz"], "");
+
+  assert_eq!("32\n1023\n", stdout(&output));
+}
+
+#[test]
+fn nth () {
+  let output = run(&["nth", "--min", "3"], "61\n62\n");
+
+  assert_eq!("00Z\n010\n", stdout(&output));
+}
+
+#[test]
+fn inspect () {
+  let output = run(&["inspect", "1a"], "");
+
+  assert!(output.status.success());
+  assert!(stdout(&output).contains("index: 72\n"));
+  assert!(stdout(&output).contains("next: 1b\n"));
+}
+
+#[test]
+fn invalid_id () {
+  let output = run(&["index", "--alphabet", "abc"], "ab\nad\n");
+
+  assert!(!output.status.success());
+  assert_eq!("1\n", stdout(&output));
+  assert!(String::from_utf8_lossy(&output.stderr).contains("invalid character 'd'"));
+}
+
+#[test]
+fn invalid_usage () {
+  assert!(!run(&[], "").status.success());
+  assert!(!run(&["frobnicate"], "").status.success());
+  assert!(!run(&["gen", "--count", "many"], "").status.success());
+  assert!(!run(&["gen", "--unknown", "1"], "").status.success());
+  assert!(!run(&["convert", "--from", "base10"], "1").status.success());
+}
+
+#[test]
+fn invalid_alphabet () {
+  let output = run(&["index", "--alphabet", "aab", "b"], "");
+
+  assert!(!output.status.success());
+  assert!(String::from_utf8_lossy(&output.stderr).contains("--alphabet: duplicate symbol \"a\""));
+
+  let output = run(&["convert", "--from", "base10", "--to", "x", "1"], "");
+
+  assert!(!output.status.success());
+  assert!(String::from_utf8_lossy(&output.stderr).contains("--to: the alphabet needs at least 2 symbols"));
+}
@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use light_id::{Alphabet, ContentId, Preset};
+
+fn hex (length: usize) -> ContentId {
+  let mut content = ContentId::new(Preset::Base16);
+  content.length(length);
+  content
+}
+
+#[test]
+fn sha256_vectors () {
+  assert_eq!(
+    "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+    hex(64).id("").to_lowercase()
+  );
+  assert_eq!(
+    "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+    hex(64).id("abc").to_lowercase()
+  );
+  assert_eq!(
+    "41edece42d63e8d9bf515a9ba6932e1c20cbc9f5a5d134645adb5db1b9737ea3",
+    hex(64).id("a".repeat(1000)).to_lowercase()
+  );
+}
+
+#[test]
+fn longer_ids_extend_shorter () {
+  let mut content = ContentId::new(Preset::Base62);
+  let long = content.length(200).id("upload");
+
+  for length in [0, 1, 8, 43, 44, 199] {
+    assert!(long.starts_with(&content.length(length).id("upload")));
+  }
+
+  assert_eq!(200, long.len());
+}
+
+#[test]
+fn uniform_symbols () {
+  let mut content = ContentId::new("012");
+  let id = content.length(30_000).id("spread");
+
+  for symbol in ['0', '1', '2'] {
+    let count = id.chars().filter(|c| *c == symbol).count();
+
+    assert!((9_000..11_000).contains(&count));
+  }
+}
+
+#[test]
+fn word_alphabet () {
+  let mut content = ContentId::new(Alphabet::words(["red", "green", "blue", "pink"], "-"));
+
+  let id = content.length(3).id("hello world");
+
+  assert_eq!(3, id.split('-').count());
+  assert!(content.length(4).id("hello world").starts_with(&id));
+}
+
+#[test]
+fn extends_on_collision () {
+  let content = hex(2);
+  let mut ids = HashSet::new();
+
+  for n in 0..1000 {
+    let id = content.unique(n.to_string(), |id| ids.contains(id));
+
+    assert!(id.len() >= 2);
+    assert!(ids.insert(id));
+  }
+
+  assert!(ids.iter().any(|id| id.len() > 2));
+}
+
+#[test]
+fn reader () {
+  let content = hex(12);
+  let data: Vec<u8> = (0..100_000).map(|n| (n % 251) as u8).collect();
+
+  // Reads in chunks that do not line up with the hash blocks
+  let reader = std::io::BufReader::with_capacity(100, &data[..]);
+
+  assert_eq!(content.id(&data), content.id_reader(reader).unwrap());
+  assert_eq!(content.id(""), content.id_reader(std::io::empty()).unwrap());
+}
@@ -0,0 +1,79 @@
+use light_id::{ConfigError, LightId, LightIdBuilder, ParseError, Preset};
+
+fn newest_first () -> LightId {
+  let mut generator = LightId::with_preset(Preset::Base16);
+
+  generator.max(4).descending(true);
+  generator
+}
+
+#[test]
+fn reverse_sorted () {
+  let mut generator = newest_first();
+  let ids: Vec<String> = (0..1000).map(|_| generator.next()).collect();
+
+  assert_eq!("ffff", ids[0]);
+
+  for pair in ids.windows(2) {
+    assert!(pair[0] > pair[1]);
+    assert_eq!(4, pair[1].len());
+  }
+}
+
+#[test]
+fn index () {
+  let generator = newest_first();
+
+  for i in (0..65536).step_by(7) {
+    assert_eq!(i, generator.index(generator.nth(i)));
+  }
+
+  assert_eq!("0000", generator.nth(generator.capacity().unwrap() - 1));
+  assert_eq!(Err(ParseError::InvalidLength), generator.try_index("fffff"));
+}
+
+#[test]
+fn step_and_offset () {
+  let mut generator = LightId::from("0123456789");
+
+  generator.max(2).step(2).offset(1).descending(true);
+
+  assert_eq!("98", generator.next());
+  assert_eq!("96", generator.next());
+  assert_eq!(1, generator.index("96"));
+  assert_eq!(Err(ParseError::OutOfSequence), generator.try_index("97"));
+  assert_eq!(Err(ParseError::OutOfSequence), generator.try_index("99"));
+}
+
+#[test]
+fn len () {
+  let mut generator = newest_first();
+
+  assert_eq!(4, generator.len());
+  assert_eq!("ffff", generator.current());
+
+  generator.descending(false);
+
+  assert_eq!(1, generator.len());
+}
+
+#[test]
+#[should_panic(expected = "Descending ids need a max length")]
+fn unbounded () {
+  let mut generator = LightId::new();
+
+  generator.descending(true).next();
+}
+
+#[test]
+fn builder () {
+  assert_eq!(
+    Err(ConfigError::Unbounded),
+    LightIdBuilder::new().descending(true).build()
+  );
+  assert_eq!(
+    Err(ConfigError::Unbounded),
+    LightIdBuilder::new().max(100).descending(true).build()
+  );
+  assert!(LightIdBuilder::new().max(3).descending(true).build().is_ok());
+}
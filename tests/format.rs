@@ -0,0 +1,82 @@
+use light_id::{LightId, ParseError};
+
+#[test]
+fn prefix () {
+  let mut gen = LightId::from("abc");
+
+  gen.prefix("ord_");
+
+  assert_eq!("ord_a", gen.next());
+  assert_eq!("ord_b", gen.next());
+}
+
+#[test]
+fn suffix () {
+  let mut gen = LightId::from("abc");
+
+  gen.suffix(".txt").skip(3);
+
+  assert_eq!("ba.txt", gen.current());
+}
+
+#[test]
+fn group () {
+  let mut gen = LightId::new();
+
+  gen.min(8).group(4, "-");
+
+  assert_eq!("0000-0000", gen.current());
+}
+
+#[test]
+fn group_uneven () {
+  let mut gen = LightId::from("0123456789");
+
+  gen.group(3, ",").skip(1234567);
+
+  assert_eq!("1,234,567", gen.current());
+}
+
+#[test]
+fn combined () {
+  let mut gen = LightId::new();
+
+  gen.prefix("ord_").group(4, "-").last("ord_1a2b-3c4d");
+
+  assert_eq!("ord_1a2b-3c4d", gen.current());
+  assert_eq!(gen.count(), gen.index("ord_1a2b-3c4d"));
+}
+
+#[test]
+fn roundtrip () {
+  let mut gen = LightId::from("abc");
+
+  gen.prefix("<").suffix(">").group(2, " ");
+
+  for i in 0..500 {
+    assert_eq!(i, gen.index(gen.nth(i)));
+  }
+}
+
+#[test]
+#[should_panic]
+fn missing_prefix () {
+  let mut gen = LightId::new();
+
+  gen.prefix("ord_");
+  gen.index("1a2b");
+}
+
+#[test]
+fn misplaced_separators () {
+  let mut gen = LightId::from("0123456789");
+
+  gen.group(2, "-");
+
+  assert_eq!("1-23", gen.nth(123));
+  assert_eq!(Ok(123), gen.try_index("1-23"));
+  assert_eq!(Err(ParseError::InvalidFormat), gen.try_index("12-3"));
+  assert_eq!(Err(ParseError::InvalidFormat), gen.try_index("123"));
+  assert_eq!(Err(ParseError::InvalidFormat), gen.try_index("1--23"));
+  assert_eq!(Err(ParseError::InvalidFormat), gen.try_index("-123"));
+}
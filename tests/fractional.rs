@@ -0,0 +1,97 @@
+use light_id::{Alphabet, FractionalIndex, ParseError, Preset};
+
+#[test]
+fn append () {
+  let mut alphabet = Alphabet::from(Preset::Base62);
+  let index = FractionalIndex::new(alphabet.sort().clone());
+  let mut keys = vec![index.first()];
+
+  for _ in 0..1000 {
+    let key = index.after(keys.last().unwrap());
+    keys.push(key);
+  }
+
+  for pair in keys.windows(2) {
+    assert!(pair[0] < pair[1]);
+  }
+}
+
+#[test]
+fn prepend () {
+  let index = FractionalIndex::new(Preset::Base16);
+  let mut keys = vec![index.first()];
+
+  for _ in 0..1000 {
+    let key = index.before(keys.last().unwrap());
+    keys.push(key);
+  }
+
+  for pair in keys.windows(2) {
+    assert!(pair[0] > pair[1]);
+  }
+}
+
+#[test]
+fn insert_between () {
+  let index = FractionalIndex::new(Preset::Base10);
+  let low = index.first();
+  let mut high = index.after(&low);
+
+  for _ in 0..500 {
+    let key = index.between(&low, &high);
+
+    assert!(low < key && key < high);
+    high = key;
+  }
+
+  let mut low = index.first();
+  let high = index.after(&low);
+
+  for _ in 0..500 {
+    let key = index.between(&low, &high);
+
+    assert!(low < key && key < high);
+    low = key;
+  }
+}
+
+#[test]
+fn grows_only_when_needed () {
+  let index = FractionalIndex::new(Preset::Base10);
+
+  assert_eq!("5", index.between("1", "9"));
+  assert_eq!("15", index.between("1", "2"));
+  assert_eq!("2", index.between("1", "25"));
+  assert_eq!("12", index.between("11", "13"));
+}
+
+#[test]
+fn words () {
+  let index = FractionalIndex::new(Alphabet::words(["ant", "bee", "cat", "dog"], "-"));
+
+  assert_eq!("cat", index.first());
+  assert_eq!("bee-cat", index.between("bee", "cat"));
+}
+
+#[test]
+fn invalid_ids () {
+  let index = FractionalIndex::new(Preset::Base10);
+
+  assert_eq!(Err(ParseError::InvalidFormat), index.try_after("10"));
+  assert_eq!(Err(ParseError::InvalidLength), index.try_before(""));
+  assert_eq!(Err(ParseError::InvalidCharacter('a')), index.try_between("1", "a"));
+  assert_eq!(Err(ParseError::Unordered), index.try_between("5", "4"));
+  assert_eq!(Err(ParseError::Unordered), index.try_between("5", "5"));
+}
+
+#[test]
+#[should_panic(expected = "Unordered")]
+fn decreasing_ids () {
+  FractionalIndex::new(Preset::Base10).between("5", "4");
+}
+
+#[test]
+#[should_panic(expected = "code-point order")]
+fn unsorted_alphabet () {
+  FractionalIndex::new(Preset::Base32);
+}
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use light_id::{Hierarchy, LightId, ParseError, Preset};
+
+#[test]
+fn document_tree () {
+  let mut hierarchy = Hierarchy::new(LightId::new());
+
+  for _ in 0..4 {
+    hierarchy.spawn("");
+  }
+
+  for _ in 0..2 {
+    hierarchy.spawn("3");
+  }
+
+  for _ in 0..73 {
+    hierarchy.spawn("3.1");
+  }
+
+  for _ in 0..8 {
+    hierarchy.spawn("3.1.1a");
+  }
+
+  assert_eq!("3.1.1a.8", hierarchy.spawn("3.1.1a"));
+  assert_eq!("3.1.1b", hierarchy.spawn("3.1"));
+  assert_eq!("4", hierarchy.spawn(""));
+}
+
+#[test]
+fn round_trip () {
+  let mut hierarchy = Hierarchy::new(LightId::with_preset(Preset::Base10));
+  hierarchy.level(LightId::new()).separator("/");
+
+  for indices in [vec![], vec![0], vec![12, 3], vec![5, 61, 62, 1000]] {
+    let path = hierarchy.path(&indices);
+
+    assert_eq!(indices, hierarchy.parse(&path));
+  }
+
+  assert_eq!("12/3/z", hierarchy.path(&[12, 3, 35]));
+}
+
+#[test]
+fn navigation () {
+  let hierarchy = Hierarchy::new(LightId::new());
+
+  assert_eq!("3.1a.8", hierarchy.next_sibling("3.1a.7"));
+  assert_eq!("3.1a.7.0", hierarchy.first_child("3.1a.7"));
+  assert_eq!(Some("3.1a".to_string()), hierarchy.parent("3.1a.7"));
+  assert_eq!("3.10", hierarchy.next_sibling("3.Z"));
+}
+
+#[test]
+fn invalid_paths () {
+  let hierarchy = Hierarchy::new(LightId::new());
+
+  assert_eq!(Err(ParseError::InvalidFormat), hierarchy.try_parse(".3"));
+  assert_eq!(Err(ParseError::InvalidFormat), hierarchy.try_parse("3."));
+  assert_eq!(Err(ParseError::InvalidCharacter('_')), hierarchy.try_parse("3._"));
+  assert_eq!(Err(ParseError::InvalidLength), hierarchy.try_next_sibling(""));
+}
+
+#[test]
+fn scopes_follow_spawns () {
+  let mut hierarchy = Hierarchy::new(LightId::new());
+
+  hierarchy.spawn("2");
+  hierarchy.spawn("2");
+
+  assert_eq!("2.2", hierarchy.scope("2").current());
+  assert_eq!("3.0", hierarchy.scope("3").current());
+  assert_eq!("2.0.0", hierarchy.scope("2.0").current());
+}
+
+#[test]
+fn scopes_and_spawns_share_children () {
+  let mut hierarchy = Hierarchy::new(LightId::new());
+  let mut issued = HashSet::new();
+
+  for n in 0..30 {
+    let paths = match n % 3 {
+      0 => vec![hierarchy.spawn("3")],
+      1 => vec![hierarchy.scope("3").next()],
+      _ => {
+        let mut scope = hierarchy.scope("3");
+        vec![scope.next(), scope.next()]
+      }
+    };
+
+    for path in paths {
+      assert!(issued.insert(path));
+    }
+  }
+
+  assert_eq!(40, issued.len());
+  assert_eq!(40, hierarchy.scope("3").count());
+  assert_eq!("3.E", hierarchy.spawn("3"));
+}
@@ -0,0 +1,164 @@
+use std::collections::HashSet;
+
+use light_id::{Alphabet, LightId, ParseError, Preset};
+
+#[test]
+fn unique () {
+  let mut generator = LightId::from("abcdef");
+  let mut issued = HashSet::new();
+
+  for _ in 0..50 {
+    assert!(issued.insert(generator.next()));
+  }
+
+  generator.migrate("012abc");
+
+  for _ in 0..500 {
+    assert!(issued.insert(generator.next()));
+  }
+
+  generator.migrate("fedcba9876543210");
+
+  for _ in 0..500 {
+    assert!(issued.insert(generator.next()));
+  }
+}
+
+#[test]
+fn decode_both_epochs () {
+  let mut generator = LightId::from("abcdef");
+  let mut issued = vec![];
+
+  for _ in 0..40 {
+    issued.push(generator.next());
+  }
+
+  generator.migrate("012abc");
+
+  for _ in 0..200 {
+    issued.push(generator.next());
+  }
+
+  for id in issued {
+    assert_eq!(id, generator.nth(generator.index(&id)));
+  }
+}
+
+#[test]
+fn keeps_count () {
+  let mut generator = LightId::new();
+
+  generator.skip(100);
+  generator.migrate("01");
+
+  assert_eq!(100, generator.count());
+  assert_eq!("1100100", generator.current());
+  assert_eq!("1B", generator.nth(99));
+  assert_eq!(99, generator.index("1B"));
+}
+
+#[test]
+fn new_ids_are_longer () {
+  let mut generator = LightId::from("ab");
+
+  generator.skip(4);
+  generator.migrate("abc");
+
+  // "a", "b", "ba" and "bb" were issued, the new ids start at 3 symbols
+  assert_eq!("baa", generator.current());
+  assert_eq!("baa", generator.next());
+  assert_eq!(3, generator.index("bb"));
+  assert_eq!(4, generator.index("baa"));
+  assert_eq!(Err(ParseError::OutOfSequence), generator.try_index("bc"));
+}
+
+#[test]
+fn bijection () {
+  let mut generator = LightId::from("abcdef");
+
+  generator.skip(10);
+  generator.migrate("012abc");
+
+  for n in 0..300 {
+    assert_eq!(n, generator.index(generator.nth(n)));
+  }
+
+  assert_eq!("ba", generator.nth(6));
+  assert_eq!(6, generator.index("ba"));
+  assert_eq!("101", generator.nth(11));
+}
+
+#[test]
+fn arithmetic_skips_old_ids () {
+  let mut generator = LightId::from("abcdef");
+  let old: HashSet<String> = (0..10).map(|n| generator.nth(n)).collect();
+
+  generator.skip(10);
+  generator.migrate("012abc");
+
+  let mut id = generator.nth(0);
+
+  for n in 1..300 {
+    id = generator.succ(&id);
+
+    assert_eq!(n >= 10, !old.contains(&id));
+    assert_eq!(generator.nth(n), id);
+  }
+
+  assert_eq!("100", generator.succ("bd"));
+  assert_eq!("bd", generator.pred("100"));
+  assert_eq!(Ok(1), generator.try_distance("bd", "100"));
+
+  for partition in generator.partition("a", "110", 4) {
+    assert_eq!(generator.index(&partition.start) + partition.count, generator.index(&partition.end));
+  }
+}
+
+#[test]
+fn normalized_old_ids () {
+  let mut alphabet = Alphabet::from(Preset::Base16);
+  alphabet.case_insensitive(true);
+
+  let mut generator = LightId::from(alphabet);
+
+  generator.skip(256);
+  generator.migrate("01");
+
+  assert_eq!("ff", generator.nth(255));
+  assert_eq!(255, generator.index("FF"));
+  assert_eq!(255, generator.index("ff"));
+}
+
+#[test]
+fn padded_ids_stay_distinct () {
+  let mut generator = LightId::from("0123456789");
+
+  generator.skip(100);
+  generator.migrate("0123456789abcdef");
+
+  assert_eq!("99", generator.nth(99));
+  assert_eq!("100", generator.nth(100));
+  assert_eq!(100, generator.index("100"));
+  assert_eq!(Err(ParseError::OutOfSequence), generator.try_index("064"));
+  assert_eq!(64, generator.index("64"));
+}
+
+#[test]
+#[should_panic(expected = "The new ids cannot be longer than the previous ones")]
+fn descending_cannot_migrate () {
+  let mut generator = LightId::from("0123456789");
+
+  generator.max(2).descending(true).skip(10);
+  generator.migrate("abcdefghij");
+}
+
+#[test]
+fn epoch () {
+  let mut generator = LightId::new();
+
+  assert_eq!(0, generator.epoch());
+
+  generator.migrate("abc").migrate("xyz");
+
+  assert_eq!(2, generator.epoch());
+}
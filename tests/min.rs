@@ -25,4 +25,35 @@ fn skip_with_min() {
   gen.skip(10).min(10);
 
   assert_eq!("aaaaaaabab", gen.current());
-}
\ No newline at end of file
+}
+
+#[test]
+fn min_multibyte() {
+  let mut gen = LightId::from("éèê");
+
+  gen.min(5).skip(4);
+
+  assert_eq!("éééèè", gen.current());
+  assert_eq!(5, gen.current().chars().count());
+}
+
+#[test]
+fn min_emoji() {
+  let mut gen = LightId::from("🍎🍌🍒");
+
+  gen.min(3);
+
+  assert_eq!("🍎🍎🍎", gen.next());
+  assert_eq!("🍎🍎🍌", gen.next());
+  assert_eq!(3, gen.len());
+}
+
+#[test]
+fn min_flags() {
+  let mut gen = LightId::from("🇫🇷🇩🇪🇮🇹");
+
+  gen.min(4).skip(5);
+
+  assert_eq!("🇫🇷🇫🇷🇩🇪🇮🇹", gen.current());
+  assert_eq!(4, gen.len());
+}
@@ -0,0 +1,90 @@
+use light_id::{IdSwitcher, MixedRadix, ParseError, Preset};
+
+fn plates () -> MixedRadix {
+  MixedRadix::new(["ABCDEFGHIJKLMNOPQRSTUVWXYZ", "ABCDEFGHIJKLMNOPQRSTUVWXYZ", "0123456789", "0123456789", "0123456789"])
+}
+
+#[test]
+fn next () {
+  let mut gen = plates();
+
+  assert_eq!("AA000", gen.next());
+  assert_eq!("AA001", gen.next());
+
+  gen.skip(999);
+
+  assert_eq!("AA999", gen.next());
+  assert_eq!("AB000", gen.next());
+}
+
+#[test]
+fn capacity () {
+  assert_eq!(Some(26 * 26 * 1000), plates().capacity());
+  assert_eq!(None, MixedRadix::new(vec![Preset::Base62; 20]).capacity());
+}
+
+#[test]
+fn last () {
+  let gen = plates();
+
+  assert_eq!("ZZ999", gen.nth(gen.capacity().unwrap() - 1));
+}
+
+#[test]
+#[should_panic]
+fn capacity_exceeded () {
+  let gen = plates();
+
+  gen.nth(gen.capacity().unwrap());
+}
+
+#[test]
+fn index () {
+  let gen = plates();
+
+  for i in (0..676000).step_by(997) {
+    assert_eq!(i, gen.index(gen.nth(i)));
+  }
+}
+
+#[test]
+fn invalid () {
+  let gen = plates();
+
+  assert_eq!(Err(ParseError::InvalidCharacter('1')), gen.try_index("A1000"));
+  assert_eq!(Err(ParseError::InvalidLength), gen.try_index("AA0000"));
+}
+
+#[test]
+fn normalization () {
+  let gen = MixedRadix::new([Preset::Crockford, Preset::Base10]);
+
+  assert_eq!(gen.index("Z9"), gen.index("z9"));
+}
+
+#[test]
+fn separator () {
+  let mut gen = MixedRadix::new(["AB", "01", "xyz"]);
+
+  gen.separator("-").skip(5);
+
+  assert_eq!("A-1-z", gen.current());
+  assert_eq!(5, gen.index("A-1-z"));
+}
+
+#[test]
+fn switch () {
+  let switch = IdSwitcher::new(Preset::Base10, plates());
+
+  assert_eq!("AB000", switch.switch("1000"));
+  assert_eq!("1000", switch.switch_reverse("AB000"));
+}
+
+#[test]
+fn switch_mixed () {
+  let switch = IdSwitcher::new(MixedRadix::new(["AB", "01"]), MixedRadix::new(["0123"]));
+
+  for (from, to) in [("A0", "0"), ("A1", "1"), ("B0", "2"), ("B1", "3")] {
+    assert_eq!(to, switch.switch(from));
+  }
+}
@@ -0,0 +1,92 @@
+use light_id::{LightId, ParseError, Preset};
+
+#[test]
+fn balanced () {
+  let generator = LightId::new();
+  let start = generator.nth(1000);
+  let end = generator.nth(1000 + 1003);
+
+  let partitions = generator.partition(&start, &end, 4);
+
+  assert_eq!(4, partitions.len());
+  assert_eq!(start, partitions[0].start);
+  assert_eq!(end, partitions[3].end);
+  assert_eq!(vec![251, 251, 251, 250], partitions.iter().map(|p| p.count).collect::<Vec<_>>());
+
+  for pair in partitions.windows(2) {
+    assert_eq!(pair[0].end, pair[1].start);
+  }
+
+  for partition in &partitions {
+    assert_eq!(partition.count, generator.distance(&partition.start, &partition.end));
+  }
+}
+
+#[test]
+fn more_partitions_than_ids () {
+  let generator = LightId::from("0123456789");
+
+  let partitions = generator.partition("5", "7", 4);
+
+  assert_eq!(vec![1, 1, 0, 0], partitions.iter().map(|p| p.count).collect::<Vec<_>>());
+  assert_eq!("7", partitions[2].start);
+  assert_eq!("7", partitions[3].end);
+}
+
+#[test]
+fn formatted () {
+  let generator = LightId::builder()
+    .alphabet(Preset::Crockford)
+    .min(6)
+    .prefix("ord_")
+    .group(3, "-")
+    .build()
+    .unwrap();
+
+  let partitions = generator.partition(generator.nth(0), generator.nth(1000), 2);
+
+  assert_eq!("ord_000-0FM", partitions[0].end);
+  assert_eq!(500, generator.index(&partitions[1].start));
+}
+
+#[test]
+fn long_ids () {
+  let generator = LightId::with_preset(Preset::Base2);
+  let start = format!("1{}", "0".repeat(100));
+  let end = format!("1{}1000", "0".repeat(96));
+
+  let partitions = generator.partition(&start, &end, 2);
+
+  assert_eq!(format!("1{}100", "0".repeat(97)), partitions[0].end);
+  assert_eq!(4, partitions[1].count);
+}
+
+#[test]
+#[should_panic(expected = "Unordered")]
+fn reversed_range () {
+  LightId::new().partition("b", "a", 2);
+}
+
+#[test]
+fn invalid_arguments () {
+  let generator = LightId::new();
+
+  assert_eq!(Err(ParseError::Unordered), generator.try_partition("b", "a", 2));
+  assert_eq!(Err(ParseError::ZeroPartitions), generator.try_partition("a", "b", 0));
+  assert_eq!(Err(ParseError::ZeroPartitions), generator.try_shard("a", 0));
+  assert_eq!(1, generator.try_partition("a", "a", 1).unwrap().len());
+}
+
+#[test]
+fn shard () {
+  let generator = LightId::new();
+  let mut counts = [0; 8];
+
+  for i in 0..8000 {
+    counts[generator.shard(generator.nth(i), 8)] += 1;
+  }
+
+  assert_eq!([1000; 8], counts);
+  assert_eq!(generator.shard("abc", 8), generator.shard("abc", 8));
+  assert_eq!(Err(ParseError::InvalidCharacter('-')), generator.try_shard("-", 8));
+}
@@ -0,0 +1,51 @@
+use light_id::{IdSwitcher, LightId, Preset, DEFAULT_CHARACTERS};
+
+#[test]
+fn with_preset () {
+  let mut gen = LightId::with_preset(Preset::Base16Upper);
+
+  gen.skip(255);
+
+  assert_eq!("FF", gen.current());
+}
+
+#[test]
+fn base62 () {
+  assert_eq!(DEFAULT_CHARACTERS, Preset::Base62.characters());
+}
+
+#[test]
+fn lengths () {
+  let expected = [2, 8, 10, 16, 16, 32, 32, 32, 32, 36, 58, 58, 62, 64, 57];
+
+  for (preset, len) in Preset::ALL.iter().zip(expected) {
+    assert_eq!(len, preset.characters().chars().count(), "{:?}", preset);
+  }
+}
+
+#[test]
+fn unique_characters () {
+  for preset in Preset::ALL {
+    let mut chars: Vec<char> = preset.characters().chars().collect();
+
+    chars.sort();
+    chars.dedup();
+
+    assert_eq!(preset.characters().len(), chars.len(), "{:?}", preset);
+  }
+}
+
+#[test]
+fn no_look_alikes () {
+  for c in ['0', 'O', '1', 'l', 'I'] {
+    assert!(!Preset::NoLookAlikes.characters().contains(c));
+  }
+}
+
+#[test]
+fn switch_presets () {
+  let switch = IdSwitcher::new(Preset::Base10, Preset::Base58);
+
+  assert_eq!("21", switch.switch("58"));
+  assert_eq!("58", switch.switch_reverse("21"));
+}
@@ -0,0 +1,81 @@
+use light_id::{LightId, ParseError, Preset, Registry};
+
+#[derive(Debug, PartialEq)]
+enum Entity {
+  User,
+  Invoice,
+}
+
+fn registry () -> Registry<Entity> {
+  let mut registry = Registry::new();
+
+  registry
+    .register(Entity::User, "usr_", LightId::with_preset(Preset::Base62))
+    .register(Entity::Invoice, "inv_", LightId::from("0123456789"));
+
+  registry
+}
+
+#[test]
+fn generate () {
+  let mut registry = registry();
+
+  registry.get_mut(&Entity::Invoice).unwrap().min(4);
+
+  assert_eq!("usr_0", registry.get_mut(&Entity::User).unwrap().next());
+  assert_eq!("inv_0000", registry.get_mut(&Entity::Invoice).unwrap().next());
+  assert_eq!("inv_0001", registry.get_mut(&Entity::Invoice).unwrap().next());
+}
+
+#[test]
+fn parse () {
+  let registry = registry();
+
+  assert_eq!(Ok((&Entity::User, 3 * 62 + 10)), registry.parse("usr_3a"));
+  assert_eq!(Ok((&Entity::Invoice, 42)), registry.parse("inv_0042"));
+}
+
+#[test]
+fn unknown_prefix () {
+  let registry = registry();
+
+  assert_eq!(Err(ParseError::UnknownPrefix), registry.parse("org_1"));
+  assert_eq!(Err(ParseError::UnknownPrefix), registry.parse("1"));
+}
+
+#[test]
+fn invalid_id () {
+  let registry = registry();
+
+  assert_eq!(Err(ParseError::InvalidCharacter('a')), registry.parse("inv_12a"));
+}
+
+#[test]
+#[should_panic]
+fn overlapping_prefix () {
+  let mut registry = Registry::new();
+
+  registry
+    .register("in", "in_", LightId::new())
+    .register("inner", "in_n_", LightId::new());
+}
+
+#[test]
+#[should_panic]
+fn overlapped_prefix () {
+  let mut registry = Registry::new();
+
+  registry
+    .register("user", "us", LightId::new())
+    .register("unit", "u", LightId::new());
+}
+
+#[test]
+#[should_panic]
+fn duplicate_prefix () {
+  let mut registry = Registry::new();
+
+  registry
+    .register(Entity::User, "x_", LightId::new())
+    .register(Entity::Invoice, "x_", LightId::new());
+}
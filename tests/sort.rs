@@ -0,0 +1,81 @@
+use std::cmp::Ordering;
+
+use light_id::{Alphabet, ConfigError, LightId, LightIdBuilder, Preset, Sorting};
+
+#[test]
+fn compare_ids () {
+  let generator = LightId::from("abcdef");
+
+  assert_eq!(Ordering::Less, generator.compare_ids("f", "ba"));
+  assert_eq!(Ordering::Equal, generator.compare_ids("bc", "bc"));
+
+  for i in (0..10000).step_by(37) {
+    assert_eq!(Ordering::Less, generator.compare_ids(generator.nth(i), generator.nth(i + 1)));
+  }
+}
+
+#[test]
+fn compare_descending_ids () {
+  let mut generator = LightId::from("0123456789");
+
+  generator.max(3).descending(true);
+
+  assert_eq!(Ordering::Less, generator.compare_ids("999", "998"));
+}
+
+#[test]
+fn is_sorted () {
+  assert!(Alphabet::from(Preset::Base16).is_sorted());
+  assert!(Alphabet::from(Preset::Crockford).is_sorted());
+  assert!(Alphabet::from(Preset::Base58).is_sorted());
+  assert!(!Alphabet::from(Preset::Base32).is_sorted());
+  assert!(!Alphabet::new("ba").is_sorted());
+  assert!(Alphabet::words(["apple", "banana", "cherry"], " ").is_sorted());
+  assert!(!Alphabet::words(["cat", "catalog"], " ").is_sorted());
+}
+
+#[test]
+fn sortable () {
+  let generator = LightIdBuilder::new()
+    .alphabet(Preset::Base16)
+    .max(6)
+    .sortable(Sorting::Reject)
+    .build()
+    .unwrap();
+
+  assert_eq!("000000", generator.current());
+
+  let ids: Vec<String> = (0..5000).map(|i| generator.nth(i * 3)).collect();
+
+  for pair in ids.windows(2) {
+    assert!(pair[0] < pair[1]);
+  }
+}
+
+#[test]
+fn sortable_resort () {
+  let generator = LightIdBuilder::new()
+    .alphabet("fedcba")
+    .max(3)
+    .sortable(Sorting::Resort)
+    .build()
+    .unwrap();
+
+  assert_eq!("aab", generator.nth(1));
+}
+
+#[test]
+fn sortable_errors () {
+  assert_eq!(
+    Err(ConfigError::UnsortedAlphabet),
+    LightIdBuilder::new().max(3).sortable(Sorting::Reject).build()
+  );
+  assert_eq!(
+    Err(ConfigError::UnsortedAlphabet),
+    LightIdBuilder::new().alphabet(Alphabet::words(["a", "ab"], "-")).max(3).sortable(Sorting::Resort).build()
+  );
+  assert_eq!(
+    Err(ConfigError::Unbounded),
+    LightIdBuilder::new().alphabet(Preset::Base16).sortable(Sorting::Reject).build()
+  );
+}
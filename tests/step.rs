@@ -0,0 +1,84 @@
+use light_id::{LightId, ParseError};
+
+fn interleaved (offset: usize) -> LightId {
+  let mut generator = LightId::from("0123456789");
+
+  generator.step(2).offset(offset);
+  generator
+}
+
+#[test]
+fn even_and_odd () {
+  let mut even = interleaved(0);
+  let mut odd = interleaved(1);
+
+  for i in 0..100 {
+    assert_eq!((2 * i).to_string(), even.next());
+    assert_eq!((2 * i + 1).to_string(), odd.next());
+  }
+}
+
+#[test]
+fn nth_and_index () {
+  let odd = interleaved(1);
+
+  assert_eq!("7", odd.nth(3));
+  assert_eq!(3, odd.index("7"));
+
+  for i in 0..1000 {
+    assert_eq!(i, odd.index(odd.nth(i)));
+  }
+}
+
+#[test]
+fn out_of_sequence () {
+  let odd = interleaved(1);
+
+  assert_eq!(Err(ParseError::OutOfSequence), odd.try_index("8"));
+
+  let mut generator = LightId::new();
+
+  generator.offset(100);
+
+  assert_eq!(Err(ParseError::OutOfSequence), generator.try_index("a"));
+}
+
+#[test]
+fn decrement () {
+  let mut odd = interleaved(1);
+
+  odd.skip(5);
+
+  assert_eq!("11", odd.current());
+  assert_eq!("9", odd.decrement().current());
+  assert_eq!("5", odd.decrement_by(2).current());
+  assert_eq!("1", odd.decrement_by(10).current());
+}
+
+#[test]
+fn count_and_len () {
+  let mut generator = LightId::from("0123456789");
+
+  generator.step(100).offset(5);
+  generator.increment();
+
+  assert_eq!(1, generator.count());
+  assert_eq!("105", generator.current());
+  assert_eq!(3, generator.len());
+}
+
+#[test]
+fn capacity () {
+  let mut generator = LightId::from("0123456789");
+
+  generator.max(2).step(3).offset(1);
+
+  assert_eq!(Some(33), generator.capacity());
+  assert_eq!("97", generator.nth(32));
+}
+
+#[test]
+#[should_panic(expected = "The step must be positive")]
+fn zero_step () {
+  LightId::new().step(0);
+}
@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+use std::thread;
+
+use light_id::{CounterStore, FileStore, LightId, MemoryStore, StoredLightId};
+
+fn issue<S: CounterStore> (store: S, block: usize, count: usize) -> Vec<String> {
+  let mut generator = StoredLightId::new(LightId::new(), store);
+
+  generator.block(block);
+  (0..count).map(|_| generator.next().unwrap()).collect()
+}
+
+#[test]
+fn memory () {
+  let store = MemoryStore::new();
+
+  assert_eq!(vec!["0", "1", "2"], issue(&store, 1, 3));
+  assert_eq!(vec!["3", "4"], issue(&store, 10, 2));
+  assert_eq!(13, store.load().unwrap());
+}
+
+#[test]
+fn memory_threads () {
+  let store = &MemoryStore::new();
+  let mut ids = HashSet::new();
+
+  thread::scope(|scope| {
+    let handles: Vec<_> = (0..8).map(|i| scope.spawn(move || issue(store, i % 3 + 1, 500))).collect();
+
+    for handle in handles {
+      for id in handle.join().unwrap() {
+        assert!(ids.insert(id));
+      }
+    }
+  });
+
+  assert_eq!(4000, ids.len());
+}
+
+#[test]
+fn capacity_exceeded () {
+  let mut generator = LightId::from("ab");
+
+  generator.max(2);
+
+  let mut generator = StoredLightId::new(generator, MemoryStore::new());
+
+  assert_eq!(vec!["a", "b", "ba", "bb"], (0..4).map(|_| generator.next().unwrap()).collect::<Vec<_>>());
+  assert!(generator.next().is_err());
+}
+
+#[test]
+fn file () {
+  let dir = tempfile::tempdir().unwrap();
+  let path = dir.path().join("counter");
+
+  assert_eq!(0, FileStore::new(&path).load().unwrap());
+  assert_eq!(vec!["0", "1"], issue(FileStore::new(&path), 1, 2));
+  assert_eq!(vec!["2", "3"], issue(FileStore::new(&path), 5, 2));
+  assert_eq!("7", std::fs::read_to_string(&path).unwrap());
+}
+
+#[test]
+fn file_compare_and_swap () {
+  let dir = tempfile::tempdir().unwrap();
+  let store = FileStore::new(dir.path().join("counter"));
+
+  assert!(store.compare_and_swap(0, 10).unwrap());
+  assert!(!store.compare_and_swap(0, 20).unwrap());
+  assert_eq!(10, store.load().unwrap());
+}
+
+#[test]
+fn file_shared () {
+  let dir = tempfile::tempdir().unwrap();
+  let path = &dir.path().join("counter");
+  let mut ids = HashSet::new();
+
+  thread::scope(|scope| {
+    let handles: Vec<_> = (0..4).map(|i| scope.spawn(move || issue(FileStore::new(path), i + 1, 100))).collect();
+
+    for handle in handles {
+      for id in handle.join().unwrap() {
+        assert!(ids.insert(id));
+      }
+    }
+  });
+
+  assert_eq!(400, ids.len());
+}
+
+#[test]
+fn invalid_file () {
+  let dir = tempfile::tempdir().unwrap();
+  let path = dir.path().join("counter");
+
+  std::fs::write(&path, "many").unwrap();
+
+  assert!(FileStore::new(&path).load().is_err());
+  assert!(FileStore::new(&path).reserve(1).is_err());
+
+  std::fs::write(&path, "").unwrap();
+
+  assert!(FileStore::new(&path).load().is_err());
+  assert!(FileStore::new(&path).reserve(1).is_err());
+  assert_eq!("", std::fs::read_to_string(&path).unwrap());
+}
+
+#[test]
+fn file_replaced_atomically () {
+  let dir = tempfile::tempdir().unwrap();
+  let path = dir.path().join("counter");
+  let store = FileStore::new(&path);
+
+  // A crash before the rename leaves the previous value in place
+  store.reserve(5).unwrap();
+  std::fs::write(dir.path().join("counter.tmp"), "").unwrap();
+
+  assert_eq!(5, store.load().unwrap());
+  assert_eq!(5, store.reserve(1).unwrap());
+  assert_eq!("6", std::fs::read_to_string(&path).unwrap());
+  assert!(dir.path().join("counter.lock").exists());
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+  use super::*;
+  use light_id::SqliteStore;
+
+  #[test]
+  fn sqlite () {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("ids.db");
+
+    assert_eq!(vec!["0", "1"], issue(SqliteStore::open(&path, "orders").unwrap(), 1, 2));
+    assert_eq!(vec!["0"], issue(SqliteStore::open(&path, "users").unwrap(), 1, 1));
+    assert_eq!(vec!["2"], issue(SqliteStore::open(&path, "orders").unwrap(), 1, 1));
+
+    let store = SqliteStore::open(&path, "orders").unwrap();
+
+    assert!(store.compare_and_swap(3, 10).unwrap());
+    assert!(!store.compare_and_swap(3, 20).unwrap());
+    assert_eq!(10, store.load().unwrap());
+  }
+
+  #[test]
+  fn sqlite_shared () {
+    let dir = tempfile::tempdir().unwrap();
+    let path = &dir.path().join("ids.db");
+    let mut ids = HashSet::new();
+
+    SqliteStore::open(path, "orders").unwrap();
+
+    thread::scope(|scope| {
+      let handles: Vec<_> = (0..4)
+        .map(|i| scope.spawn(move || issue(SqliteStore::open(path, "orders").unwrap(), i + 1, 50)))
+        .collect();
+
+      for handle in handles {
+        for id in handle.join().unwrap() {
+          assert!(ids.insert(id));
+        }
+      }
+    });
+
+    assert_eq!(200, ids.len());
+  }
+}
@@ -0,0 +1,126 @@
+use light_id::{ConfigError, ParseError, Template};
+
+fn invoices () -> Template {
+  let mut template = Template::new("INV-{year}-{seq:6:0-9}-{check}").unwrap();
+
+  template.set("year", "2024");
+  template
+}
+
+#[test]
+fn next () {
+  let mut template = invoices();
+
+  template.sequence().skip(41);
+
+  assert_eq!("INV-2024-000041-4", template.next());
+  assert_eq!("INV-2024-000042-2", template.next());
+}
+
+#[test]
+fn current_year () {
+  let template = Template::new("{year}/{seq}").unwrap();
+  let id = template.current();
+  let year: i64 = id.split('/').next().unwrap().parse().unwrap();
+
+  assert!(year >= 2024);
+}
+
+#[test]
+fn roundtrip () {
+  let template = invoices();
+
+  for i in (0..100000).step_by(7) {
+    let parsed = template.parse(template.nth(i)).unwrap();
+
+    assert_eq!(i, parsed.index);
+    assert_eq!(Some("2024"), parsed.get("year"));
+  }
+}
+
+#[test]
+fn wide_sequence () {
+  let template = invoices();
+  let id = template.nth(12345678);
+
+  assert_eq!("INV-2024-12345678-", &id[..18]);
+  assert_eq!(12345678, template.parse(id).unwrap().index);
+}
+
+#[test]
+fn checksum () {
+  let template = invoices();
+
+  assert!(template.parse("INV-2024-000042-2").is_ok());
+  assert_eq!(Err(ParseError::InvalidChecksum), template.parse("INV-2024-000042-3"));
+  assert_eq!(Err(ParseError::InvalidChecksum), template.parse("INV-2024-000024-2"));
+}
+
+#[test]
+fn literals () {
+  let template = invoices();
+
+  assert_eq!(Err(ParseError::InvalidFormat), template.parse("INV2024-000042-3"));
+  assert_eq!(Err(ParseError::InvalidFormat), template.parse("INV-2024-00042-3"));
+  assert_eq!(Err(ParseError::InvalidFormat), template.parse("INV-2024-000042-3-"));
+}
+
+#[test]
+fn variables () {
+  let mut template = Template::new("{region}.{env}:{seq:4:A-F}").unwrap();
+
+  template.set("region", "eu-west").set("env", "prod").sequence().skip(6);
+
+  assert_eq!("eu-west.prod:AABA", template.current());
+  assert_eq!(Some("eu-west"), template.parse("eu-west.prod:FFFF").unwrap().get("region"));
+  assert_eq!(Err(ParseError::InvalidFormat), template.parse("us.dev:FFFF"));
+
+  let template = Template::new("{region}.{env}:{seq:4:A-F}").unwrap();
+  let parsed = template.parse("us.dev:FFFF").unwrap();
+
+  assert_eq!(Some("us"), parsed.get("region"));
+  assert_eq!(Some("dev"), parsed.get("env"));
+  assert_eq!(6 * 6 * 6 * 6 - 1, parsed.index);
+}
+
+#[test]
+fn adjacent_variables () {
+  let mut template = Template::new("{a}{b}{c}{d}{e}{f}{g}-{seq:1:0-9}").unwrap();
+
+  assert_eq!(Err(ParseError::InvalidFormat), template.parse("x".repeat(200)));
+  assert_eq!(Err(ParseError::InvalidFormat), template.parse("-".repeat(200)));
+  assert_eq!(Some("xxxx"), template.parse("xxxxxxxxxx-5").unwrap().get("a"));
+
+  template.set("a", "1").set("b", "22");
+
+  assert_eq!(Some("xx"), template.parse("122xxxxxx-5").unwrap().get("c"));
+  assert_eq!(Err(ParseError::InvalidFormat), template.parse("212xxxxxx-5"));
+}
+
+#[test]
+fn preset_alphabet () {
+  let template = Template::new("{seq:4:crockford}").unwrap();
+
+  assert_eq!(31, template.parse("000z").unwrap().index);
+}
+
+#[test]
+fn escaped_braces () {
+  let template = Template::new("{{{seq:2:0-9}}}").unwrap();
+
+  assert_eq!("{07}", template.nth(7));
+  assert_eq!(7, template.parse("{07}").unwrap().index);
+}
+
+#[test]
+#[should_panic]
+fn missing_value () {
+  Template::new("{region}-{seq}").unwrap().current();
+}
+
+#[test]
+fn invalid () {
+  for pattern in ["INV", "{seq}{seq}", "{seq:x}", "{seq:2:9-0}", "{seq:2:a}", "{seq", "seq}", "{}", "{seq:2:0-9:x}"] {
+    assert!(matches!(Template::new(pattern), Err(ConfigError::InvalidTemplate(_))), "{}", pattern);
+  }
+}
@@ -0,0 +1,200 @@
+use std::collections::HashSet;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use light_id::{CounterStore, LightId, StoredLightId, TicketClient, TicketServer};
+
+fn start (directory: &Path) -> SocketAddr {
+  let server = TicketServer::bind("127.0.0.1:0", directory).unwrap();
+  let address = server.local_addr().unwrap();
+
+  thread::spawn(move || server.serve());
+  address
+}
+
+fn issue (address: SocketAddr, block: usize, count: usize) -> Vec<String> {
+  let client = TicketClient::connect(address, "orders").unwrap();
+  let mut generator = StoredLightId::new(LightId::new(), client);
+
+  generator.block(block);
+  (0..count).map(|_| generator.next().unwrap()).collect()
+}
+
+#[test]
+fn shared_sequence () {
+  let dir = tempfile::tempdir().unwrap();
+  let address = start(dir.path());
+  let mut ids = HashSet::new();
+
+  let handles: Vec<_> = (0..4).map(|i| thread::spawn(move || issue(address, i * 10 + 1, 200))).collect();
+
+  for handle in handles {
+    for id in handle.join().unwrap() {
+      assert!(ids.insert(id));
+    }
+  }
+
+  assert_eq!(800, ids.len());
+}
+
+#[test]
+fn restart () {
+  let dir = tempfile::tempdir().unwrap();
+
+  assert_eq!(vec!["0", "1", "2"], issue(start(dir.path()), 10, 3));
+  assert_eq!(vec!["a", "b"], issue(start(dir.path()), 10, 2));
+}
+
+#[test]
+fn counters () {
+  let dir = tempfile::tempdir().unwrap();
+  let address = start(dir.path());
+
+  let orders = TicketClient::connect(address, "orders").unwrap();
+  let users = TicketClient::connect(address, "users").unwrap();
+
+  assert_eq!(0, orders.reserve(5).unwrap());
+  assert_eq!(0, users.reserve(1).unwrap());
+  assert!(orders.compare_and_swap(5, 8).unwrap());
+  assert!(!orders.compare_and_swap(5, 9).unwrap());
+  assert_eq!(8, orders.load().unwrap());
+  assert_eq!(1, users.load().unwrap());
+}
+
+#[test]
+fn invalid_requests () {
+  let dir = tempfile::tempdir().unwrap();
+  let address = start(dir.path());
+
+  assert!(TicketClient::connect(address, "../orders").unwrap().load().is_err());
+
+  let mut stream = TcpStream::connect(address).unwrap();
+  let mut reader = BufReader::new(stream.try_clone().unwrap());
+  let mut response = String::new();
+
+  stream.write_all(b"LEASE orders many\n").unwrap();
+  reader.read_line(&mut response).unwrap();
+
+  assert_eq!("ERR invalid number \"many\"\n", response);
+}
+
+#[test]
+fn long_requests () {
+  let dir = tempfile::tempdir().unwrap();
+  let address = start(dir.path());
+
+  let mut stream = TcpStream::connect(address).unwrap();
+  let mut reader = BufReader::new(stream.try_clone().unwrap());
+  let mut response = String::new();
+
+  stream.write_all(format!("LOAD {}{}\n", "a".repeat(200), " ".repeat(800)).as_bytes()).unwrap();
+  reader.read_line(&mut response).unwrap();
+
+  assert_eq!("OK 0\n", response);
+
+  // The server stops reading after the limit, and closes the connection
+  let _ = stream.write_all(&[b'a'; 100_000]);
+  response.clear();
+  reader.read_line(&mut response).unwrap();
+
+  assert_eq!("ERR request too long\n", response);
+
+  response.clear();
+
+  assert_eq!(0, reader.read_line(&mut response).unwrap_or(0));
+}
+
+#[test]
+fn idle_connections () {
+  static ERRORS: AtomicUsize = AtomicUsize::new(0);
+
+  let dir = tempfile::tempdir().unwrap();
+  let mut server = TicketServer::bind("127.0.0.1:0", dir.path()).unwrap();
+  let address = server.local_addr().unwrap();
+
+  server.timeout(Duration::from_millis(200)).on_error(|_| {
+    ERRORS.fetch_add(1, Ordering::SeqCst);
+  });
+  thread::spawn(move || server.serve());
+
+  let start = Instant::now();
+  let mut response = String::new();
+
+  // The server hangs up on a silent client
+  assert_eq!(0, BufReader::new(TcpStream::connect(address).unwrap()).read_line(&mut response).unwrap_or(0));
+  assert!(start.elapsed() < Duration::from_secs(10));
+
+  thread::sleep(Duration::from_millis(100));
+
+  assert_eq!(1, ERRORS.load(Ordering::SeqCst));
+}
+
+#[test]
+fn max_connections () {
+  let dir = tempfile::tempdir().unwrap();
+  let mut server = TicketServer::bind("127.0.0.1:0", dir.path()).unwrap();
+  let address = server.local_addr().unwrap();
+
+  server.max_connections(1);
+  thread::spawn(move || server.serve());
+
+  let first = TicketClient::connect(address, "orders").unwrap();
+
+  assert_eq!(0, first.load().unwrap());
+
+  let mut stream = TcpStream::connect(address).unwrap();
+  let mut response = String::new();
+
+  stream.write_all(b"LOAD orders\n").unwrap();
+  BufReader::new(stream).read_line(&mut response).unwrap();
+
+  assert_eq!("ERR too many connections\n", response);
+
+  drop(first);
+  thread::sleep(Duration::from_millis(100));
+
+  assert_eq!(0, TicketClient::connect(address, "orders").unwrap().load().unwrap());
+}
+
+#[test]
+fn unresponsive_server () {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let address = listener.local_addr().unwrap();
+
+  // Accepts the connections but never answers
+  thread::spawn(move || listener.incoming().collect::<Vec<_>>());
+
+  let mut client = TicketClient::connect(address, "orders").unwrap();
+  let start = Instant::now();
+
+  client.timeout(Duration::from_millis(200));
+
+  assert!(client.reserve(10).is_err());
+  assert!(start.elapsed() < Duration::from_secs(10));
+}
+
+#[test]
+fn reconnect () {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let address = listener.local_addr().unwrap();
+
+  // Answers a single request per connection, then hangs up
+  thread::spawn(move || {
+    for (start, stream) in [0, 10].into_iter().zip(listener.incoming()) {
+      let mut stream = stream.unwrap();
+      let mut request = String::new();
+
+      BufReader::new(&stream).read_line(&mut request).unwrap();
+      stream.write_all(format!("OK {}\n", start).as_bytes()).unwrap();
+    }
+  });
+
+  let client = TicketClient::connect(address, "orders").unwrap();
+
+  assert_eq!(0, client.reserve(10).unwrap());
+  assert_eq!(10, client.reserve(10).unwrap());
+}
@@ -0,0 +1,112 @@
+use std::collections::HashSet;
+
+use light_id::{IdSwitcher, LightId, ParseError};
+
+fn duplicate<T: Clone> (value: &T) -> (T, T) {
+  (value.clone(), value.clone())
+}
+
+#[test]
+fn default () {
+  assert_eq!(LightId::new(), LightId::default());
+}
+
+#[test]
+fn clone () {
+  let mut gen = LightId::from("abc");
+  gen.min(4).skip(7);
+
+  let (a, b) = duplicate(&gen);
+
+  assert_eq!(gen, a);
+  assert_eq!("aacb", b.current());
+
+  let switch = IdSwitcher::new("01", "0123456789");
+
+  assert_eq!(switch, switch.clone());
+}
+
+#[test]
+fn debug () {
+  let gen = LightId::from("abc");
+
+  assert!(format!("{:?}", gen).starts_with("LightId"));
+  assert!(format!("{:?}", IdSwitcher::new("01", "abc")).starts_with("IdSwitcher"));
+}
+
+#[test]
+fn display () {
+  let mut gen = LightId::from("abc");
+
+  gen.skip(5);
+
+  assert_eq!("bc", gen.to_string());
+  assert_eq!("id: bc", format!("id: {}", gen));
+}
+
+#[test]
+fn eq () {
+  let mut a = LightId::new();
+  let mut b = LightId::new();
+
+  assert_eq!(a, b);
+
+  a.min(4);
+
+  assert_ne!(a, b);
+
+  b.min(4).prefix("x_");
+
+  assert_ne!(a, b);
+}
+
+#[test]
+fn hash () {
+  let mut set = HashSet::new();
+
+  set.insert(LightId::new());
+  set.insert(LightId::default());
+  set.insert(LightId::from("abc"));
+
+  assert_eq!(2, set.len());
+}
+
+#[test]
+fn ord () {
+  let mut a = LightId::from("abc");
+  let b = LightId::new();
+
+  a.increment();
+
+  assert!(a > b);
+
+  a.decrement();
+
+  // Same count, ordered by alphabet
+  assert!(a != b);
+  assert!(a.partial_cmp(&b).unwrap().is_ne());
+}
+
+#[test]
+fn from_str () {
+  let gen: LightId = "Z".parse().unwrap();
+
+  assert_eq!(61, gen.count());
+  assert_eq!("Z", gen.current());
+  assert_eq!(Err(ParseError::InvalidCharacter('_')), "_".parse::<LightId>());
+}
+
+#[test]
+fn switcher_ord () {
+  let mut switchers = [
+    IdSwitcher::new("0123456789", "ab"),
+    IdSwitcher::new("01", "abc"),
+    IdSwitcher::new("01", "ab"),
+  ];
+
+  switchers.sort();
+
+  assert_eq!(IdSwitcher::new("01", "ab"), switchers[0]);
+  assert_eq!(IdSwitcher::new("01", "abc"), switchers[1]);
+  assert_eq!(IdSwitcher::new("0123456789", "ab"), switchers[2]);
+}
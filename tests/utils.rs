@@ -1,4 +1,4 @@
-use light_id::LightId;
+use light_id::{LightId, ParseError};
 
 #[test]
 fn len () {
@@ -32,4 +32,51 @@ fn last () {
   gen.last("caa");
 
   assert_eq!(18, gen.count());
-}
\ No newline at end of file
+}
+
+#[test]
+fn len_multibyte () {
+  let mut gen = LightId::from("αβγ");
+
+  gen.increment_by(9);
+
+  assert_eq!("βαα", gen.current());
+  assert_eq!(3, gen.len());
+}
+
+#[test]
+fn last_multibyte () {
+  let mut gen = LightId::from("αβγ");
+
+  gen.last("γαα");
+
+  assert_eq!(18, gen.count());
+}
+
+#[test]
+fn index_graphemes () {
+  let gen = LightId::from("e\u{301}a\u{308}o");
+
+  assert_eq!(3, gen.index("a\u{308}e\u{301}"));
+
+  for i in 0..100 {
+    assert_eq!(i, gen.index(gen.nth(i)));
+  }
+}
+
+#[test]
+fn index_flags () {
+  let gen = LightId::from("🇫🇷🇩🇪🇮🇹");
+
+  for i in 0..100 {
+    assert_eq!(i, gen.index(gen.nth(i)));
+  }
+}
+
+#[test]
+fn invalid_symbols () {
+  let gen = LightId::from("🇫🇷🇩🇪🇮🇹");
+
+  assert_eq!(Err(ParseError::InvalidCharacter('x')), gen.try_index("🇫🇷x"));
+  assert_eq!(Err(ParseError::InvalidSymbol("🇬🇧".to_string())), gen.try_index("🇫🇷🇬🇧"));
+}
@@ -0,0 +1,86 @@
+use light_id::{Alphabet, IdSwitcher, LightId, Preset};
+
+fn fruits () -> Alphabet {
+  Alphabet::words(["apple", "banana", "cherry"], "-")
+}
+
+#[test]
+fn next () {
+  let mut gen = LightId::from(fruits());
+
+  gen.min(3);
+
+  assert_eq!("apple-apple-apple", gen.next());
+  assert_eq!("apple-apple-banana", gen.next());
+  assert_eq!("apple-apple-cherry", gen.next());
+  assert_eq!("apple-banana-apple", gen.next());
+}
+
+#[test]
+fn index () {
+  let gen = LightId::from(fruits());
+
+  for i in 0..200 {
+    assert_eq!(i, gen.index(gen.nth(i)));
+  }
+}
+
+#[test]
+fn len () {
+  let mut gen = LightId::from(fruits());
+
+  gen.skip(9);
+
+  assert_eq!("banana-apple-apple", gen.current());
+  assert_eq!(3, gen.len());
+}
+
+#[test]
+fn numbers_as_words () {
+  let mut words: Vec<String> = ["brave", "calm"].iter().map(|w| w.to_string()).collect();
+  words.extend((0..100).map(|n| n.to_string()));
+
+  let gen = LightId::from(Alphabet::words(words, "-"));
+
+  assert_eq!(102 + 44, gen.index("brave-calm-42"));
+}
+
+#[test]
+fn case_insensitive () {
+  let mut alphabet = fruits();
+  alphabet.case_insensitive(true);
+
+  let gen = LightId::from(alphabet);
+
+  assert_eq!(gen.index("banana-cherry"), gen.index("Banana-CHERRY"));
+}
+
+#[test]
+fn grouped () {
+  let mut gen = LightId::from(fruits());
+
+  gen.min(4).group(2, " / ").skip(10);
+
+  assert_eq!("apple-banana / apple-banana", gen.current());
+  assert_eq!(10, gen.index("apple-banana / apple-banana"));
+}
+
+#[test]
+fn switch () {
+  let switch = IdSwitcher::new(Preset::Base10, fruits());
+
+  assert_eq!("banana-apple-banana", switch.switch("10"));
+  assert_eq!("10", switch.switch_reverse("banana-apple-banana"));
+}
+
+#[test]
+#[should_panic]
+fn invalid_word () {
+  Alphabet::words(["a-b", "c"], "-");
+}
+
+#[test]
+#[should_panic]
+fn unknown_word () {
+  LightId::from(fruits()).index("apple-kiwi");
+}